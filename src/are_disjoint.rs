@@ -4,6 +4,10 @@ use crate::error::Error;
 pub enum AreAllDisjoint<T = ()> {
     No(T),
     Yes,
+    /// Only the arguments whose element is `true` (the `mut` arguments of `multindex_mixed`)
+    /// must be disjoint from every other argument,
+    /// the rest can overlap each other.
+    OnlyMut(&'static [bool]),
 }
 
 impl AreAllDisjoint {
@@ -11,6 +15,17 @@ impl AreAllDisjoint {
     pub const NO: Self = AreAllDisjoint::No(());
 }
 
+impl<T> AreAllDisjoint<T> {
+    /// Whether the arguments at the `left` and `right` positions must not overlap.
+    pub const fn must_be_disjoint(&self, left: usize, right: usize) -> bool {
+        match *self {
+            AreAllDisjoint::No(_) => false,
+            AreAllDisjoint::Yes => true,
+            AreAllDisjoint::OnlyMut(is_mut) => is_mut[left] || is_mut[right],
+        }
+    }
+}
+
 impl AreAllDisjoint<()> {
     pub const fn with_dummy_error(self) -> AreAllDisjoint<Error> {
         match self {
@@ -22,6 +37,7 @@ impl AreAllDisjoint<()> {
                 AreAllDisjoint::No(err)
             }
             AreAllDisjoint::Yes => AreAllDisjoint::Yes,
+            AreAllDisjoint::OnlyMut(is_mut) => AreAllDisjoint::OnlyMut(is_mut),
         }
     }
 }
@@ -29,8 +45,10 @@ impl AreAllDisjoint<Error> {
     pub const fn check_is_expected<T>(self, expected: &AreAllDisjoint<T>) -> Result<(), Error> {
         match (self, expected) {
             (AreAllDisjoint::Yes, _) => Ok(()),
+            (AreAllDisjoint::OnlyMut(_), _) => Ok(()),
             (_, AreAllDisjoint::No(_)) => Ok(()),
             (AreAllDisjoint::No(x), AreAllDisjoint::Yes) => Err(x),
+            (AreAllDisjoint::No(x), AreAllDisjoint::OnlyMut(_)) => Err(x),
         }
     }
 }
@@ -0,0 +1,257 @@
+//! Indexing of arrays whose arguments are bounds checked at compile-time,
+//! used by the `multindex_arr*` macros.
+//!
+//! The bounds checks are associated constants that are evaluated
+//! when the indexing functions are instantiated,
+//! so they error in `cargo build`, but not necessarily in `cargo check`.
+
+use crate::{
+    error::{Error, OutOfBounds},
+    index_argument::{IK_Index, IK_Range, IK_RangeFrom, IndexArgument},
+    panic_message::PanicMessage,
+};
+
+use core::{marker::PhantomData, mem::ManuallyDrop, ptr};
+
+/// Indexes an array of unknown length with an argument
+/// (starting at `START`, and `LEN` elements long) that was normalized at compile-time.
+///
+/// `IK` is the type-level `IndexKind` of the argument,
+/// and `ARG` is the position of the argument in the macro invocation.
+pub struct ArrayIndexer<IK, const ARG: u16, const START: usize, const LEN: usize> {
+    _kind: PhantomData<fn() -> IK>,
+}
+
+impl<IK, const ARG: u16, const START: usize, const LEN: usize> ArrayIndexer<IK, ARG, START, LEN> {
+    pub const NEW: Self = Self { _kind: PhantomData };
+}
+
+struct AssertInBounds<const N: usize, const ARG: u16, const START: usize, const LEN: usize>;
+
+impl<const N: usize, const ARG: u16, const START: usize, const LEN: usize>
+    AssertInBounds<N, ARG, START, LEN>
+{
+    const OK: () = if START + LEN > N {
+        let error = Error::OutOfBounds(OutOfBounds {
+            slice_len: N,
+            max_bounded_end: START + LEN,
+            argument: ARG,
+        });
+        error.panic(&[], &[])
+    };
+}
+
+struct AssertRestLen<const N: usize, const ARG: u16, const START: usize, const M: usize>;
+
+impl<const N: usize, const ARG: u16, const START: usize, const M: usize>
+    AssertRestLen<N, ARG, START, M>
+{
+    const OK: () = {
+        let () = AssertInBounds::<N, ARG, START, 0>::OK;
+        if N - START != M {
+            let msg = PanicMessage::new()
+                .str("multindex error: argument ")
+                .usize(ARG as usize)
+                .str(" is an array of ")
+                .usize(N - START)
+                .str(" elements, but it was inferred to be an array of ")
+                .usize(M)
+                .str(" elements");
+            panic!("{}", msg.as_str())
+        }
+    };
+}
+
+struct AssertReachesEnd<const N: usize, const END: usize>;
+
+impl<const N: usize, const END: usize> AssertReachesEnd<N, END> {
+    const OK: () = if END < N {
+        let msg = PanicMessage::new()
+            .str("multindex error: the arguments end at ")
+            .usize(END)
+            .str(", leaving the last ")
+            .usize(N - END)
+            .str(" elements of the array uncovered");
+        panic!("{}", msg.as_str())
+    };
+}
+
+/// Checks that the arguments of the exhaustive array macros reach the end of the array.
+///
+/// `END` is the end of the last argument, `usize::MAX` if it's an unbounded range.
+pub struct ArrayCoverage<const END: usize>;
+
+impl<const END: usize> ArrayCoverage<END> {
+    pub const NEW: Self = Self;
+
+    #[inline(always)]
+    pub fn assert_reaches_end<T, const N: usize>(self, _: *const [T; N]) {
+        let () = AssertReachesEnd::<N, END>::OK;
+    }
+}
+
+impl<const ARG: u16, const START: usize, const LEN: usize> ArrayIndexer<IK_Index, ARG, START, LEN> {
+    #[inline(always)]
+    pub unsafe fn index_ptr<T, const N: usize>(
+        self,
+        base: *const [T; N],
+        _: PhantomData<&T>,
+    ) -> &T {
+        let () = AssertInBounds::<N, ARG, START, LEN>::OK;
+        &*(base as *const T).add(START)
+    }
+
+    #[inline(always)]
+    pub unsafe fn index_ptr_mut<T, const N: usize>(
+        self,
+        base: *mut [T; N],
+        _: PhantomData<&mut T>,
+    ) -> &mut T {
+        let () = AssertInBounds::<N, ARG, START, LEN>::OK;
+        &mut *(base as *mut T).add(START)
+    }
+
+    #[inline(always)]
+    pub unsafe fn read_ptr<T, const N: usize>(self, base: *const [T; N]) -> T {
+        let () = AssertInBounds::<N, ARG, START, LEN>::OK;
+        ptr::read((base as *const T).add(START))
+    }
+}
+
+impl<const ARG: u16, const START: usize, const LEN: usize> ArrayIndexer<IK_Range, ARG, START, LEN> {
+    #[inline(always)]
+    pub unsafe fn index_ptr<T, const N: usize>(
+        self,
+        base: *const [T; N],
+        _: PhantomData<&T>,
+    ) -> &[T; LEN] {
+        let () = AssertInBounds::<N, ARG, START, LEN>::OK;
+        &*((base as *const T).add(START) as *const [T; LEN])
+    }
+
+    #[inline(always)]
+    pub unsafe fn index_ptr_mut<T, const N: usize>(
+        self,
+        base: *mut [T; N],
+        _: PhantomData<&mut T>,
+    ) -> &mut [T; LEN] {
+        let () = AssertInBounds::<N, ARG, START, LEN>::OK;
+        &mut *((base as *mut T).add(START) as *mut [T; LEN])
+    }
+
+    #[inline(always)]
+    pub unsafe fn read_ptr<T, const N: usize>(self, base: *const [T; N]) -> [T; LEN] {
+        let () = AssertInBounds::<N, ARG, START, LEN>::OK;
+        ptr::read((base as *const T).add(START) as *const [T; LEN])
+    }
+}
+
+/// The trailing unbounded range returns an `[T; M]` array,
+/// `M` can't be computed from `N - START` in the signature,
+/// so it's inferred from the caller and then checked to be equal to that.
+impl<const ARG: u16, const START: usize, const LEN: usize>
+    ArrayIndexer<IK_RangeFrom, ARG, START, LEN>
+{
+    #[inline(always)]
+    pub unsafe fn index_ptr<T, const N: usize, const M: usize>(
+        self,
+        base: *const [T; N],
+        _: PhantomData<&T>,
+    ) -> &[T; M] {
+        let () = AssertRestLen::<N, ARG, START, M>::OK;
+        &*((base as *const T).add(START) as *const [T; M])
+    }
+
+    #[inline(always)]
+    pub unsafe fn index_ptr_mut<T, const N: usize, const M: usize>(
+        self,
+        base: *mut [T; N],
+        _: PhantomData<&mut T>,
+    ) -> &mut [T; M] {
+        let () = AssertRestLen::<N, ARG, START, M>::OK;
+        &mut *((base as *mut T).add(START) as *mut [T; M])
+    }
+
+    #[inline(always)]
+    pub unsafe fn read_ptr<T, const N: usize, const M: usize>(self, base: *const [T; N]) -> [T; M] {
+        let () = AssertRestLen::<N, ARG, START, M>::OK;
+        ptr::read((base as *const T).add(START) as *const [T; M])
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Used to decompose an array reference into its parts
+///
+/// The `lifetime` field can be used to create references with the same lifetime.
+pub struct ArrayParts<'a, T, const N: usize> {
+    pub ptr: *const [T; N],
+    pub lifetime: PhantomData<&'a T>,
+}
+
+impl<'a, T, const N: usize> ArrayParts<'a, T, N> {
+    #[inline(always)]
+    pub const fn new(array: &'a [T; N]) -> Self {
+        Self {
+            ptr: array,
+            lifetime: PhantomData,
+        }
+    }
+}
+
+/// Used to decompose a mutable array reference into its parts
+///
+/// The `lifetime` field can be used to create references with the same lifetime.
+pub struct ArrayPartsMut<'a, T, const N: usize> {
+    pub ptr: *mut [T; N],
+    pub lifetime: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, const N: usize> ArrayPartsMut<'a, T, N> {
+    #[inline(always)]
+    pub fn new(array: &'a mut [T; N]) -> Self {
+        Self {
+            ptr: array,
+            lifetime: PhantomData,
+        }
+    }
+}
+
+/// Used to move the elements out of an array, in the `multisplit` macro.
+///
+/// The elements are moved out with `ArrayIndexer::read_ptr`,
+/// and the rest of them are dropped with `drop_uncovered`.
+pub struct ArrayOwned<T, const N: usize> {
+    array: ManuallyDrop<[T; N]>,
+}
+
+impl<T, const N: usize> ArrayOwned<T, N> {
+    #[inline(always)]
+    pub fn new(array: [T; N]) -> Self {
+        Self {
+            array: ManuallyDrop::new(array),
+        }
+    }
+
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const [T; N] {
+        &*self.array
+    }
+
+    /// Drops the elements that aren't covered by any of the arguments.
+    ///
+    /// # Safety
+    ///
+    /// This must only be called once, after all the arguments were read with `read_ptr`.
+    pub unsafe fn drop_uncovered(&mut self, ind_args: &[IndexArgument]) {
+        let base = &mut *self.array as *mut [T; N] as *mut T;
+        for i in 0..N {
+            let is_covered = ind_args
+                .iter()
+                .any(|ind_arg| ind_arg.start() <= i && i < ind_arg.saturated_end());
+            if !is_covered {
+                ptr::drop_in_place(base.add(i));
+            }
+        }
+    }
+}
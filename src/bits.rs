@@ -0,0 +1,231 @@
+//! Items for indexing the bits of integers and byte slices with the [`bitindex`] macro.
+//!
+//! Bits are numbered from the least significant bit,
+//! so bit `0` of an integer is the `1` bit,
+//! and bit `n` of a byte slice is bit `n % 8` of the byte at index `n / 8`.
+//!
+//! [`bitindex`]: ../macro.bitindex.html
+
+use crate::index_argument::IndexArgument;
+
+use core::{
+    cell::Cell,
+    fmt::{self, Debug},
+};
+
+/// The maximum amount of bits in a [`BitField`].
+///
+/// [`BitField`]: ./struct.BitField.html
+pub const MAX_FIELD_BITS: usize = 64;
+
+/// A handle to a range of bits, returned by the [`bitindex`] macro.
+///
+/// The value of the bits is read with [`get`](#method.get),
+/// and overwritten with [`set`](#method.set).
+///
+/// [`bitindex`]: ../macro.bitindex.html
+pub struct BitField<'a, S: ?Sized> {
+    storage: &'a S,
+    start: usize,
+    width: usize,
+}
+
+impl<'a, S> BitField<'a, S>
+where
+    S: ?Sized + BitStorage,
+{
+    #[doc(hidden)]
+    #[inline]
+    pub fn new(storage: &'a S, ind_arg: &IndexArgument) -> Self {
+        let start = ind_arg.start();
+        let width = match ind_arg.end() {
+            Some(end) => end - start,
+            None => storage.bit_len() - start,
+        };
+        // Bounded ranges are checked at compile-time by `IndexArgument::check_field_widths`,
+        // this can only fail for a trailing unbounded range.
+        assert!(
+            width <= MAX_FIELD_BITS,
+            "bit fields can't be longer than {} bits, found one that's {} bits long",
+            MAX_FIELD_BITS,
+            width,
+        );
+        Self {
+            storage,
+            start,
+            width,
+        }
+    }
+
+    /// The position of the first bit of this field.
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The amount of bits in this field.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Reads the bits of this field, with the first bit of the field as the least significant bit.
+    #[inline]
+    pub fn get(&self) -> u64 {
+        self.storage.get_bits(self.start, self.width)
+    }
+
+    /// Overwrites the bits of this field,
+    /// ignoring the bits of `value` that don't fit in the field.
+    #[inline]
+    pub fn set(&mut self, value: u64) {
+        self.storage.set_bits(self.start, self.width, value)
+    }
+}
+
+impl<S> Debug for BitField<'_, S>
+where
+    S: ?Sized + BitStorage,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BitField")
+            .field("start", &self.start)
+            .field("width", &self.width)
+            .field("value", &self.get())
+            .finish()
+    }
+}
+
+#[inline(always)]
+const fn low_mask(width: usize) -> u64 {
+    if width >= 64 {
+        !0
+    } else {
+        (1 << width) - 1
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// Something whose bits can be read and written through a shared reference.
+///
+/// This is implemented for `Cell`s of unsigned integers, and for `[Cell<u8>]`.
+pub trait BitStorage: Sealed {
+    /// The amount of bits in this storage.
+    fn bit_len(&self) -> usize;
+
+    /// Reads `width` bits (at most 64) starting at `start`.
+    fn get_bits(&self, start: usize, width: usize) -> u64;
+
+    /// Writes the low `width` bits of `value` (at most 64) starting at `start`.
+    fn set_bits(&self, start: usize, width: usize, value: u64);
+}
+
+/// Converts a mutable reference to an integer or byte slice into a [`BitStorage`],
+/// used by the [`bitindex`] macro.
+///
+/// [`BitStorage`]: ./trait.BitStorage.html
+/// [`bitindex`]: ../macro.bitindex.html
+#[allow(non_camel_case_types)]
+pub trait AsBitStorage: Sealed {
+    /// The storage that the bits are accessed through.
+    type Storage: ?Sized + BitStorage;
+
+    #[doc(hidden)]
+    fn _11748397628858797803_as_bit_storage(&mut self) -> &Self::Storage;
+}
+
+macro_rules! impl_int_storage {
+    ($($int:ident)*) => {
+        $(
+            impl Sealed for $int {}
+            impl Sealed for Cell<$int> {}
+
+            impl BitStorage for Cell<$int> {
+                #[inline]
+                fn bit_len(&self) -> usize {
+                    core::mem::size_of::<$int>() * 8
+                }
+
+                #[inline]
+                fn get_bits(&self, start: usize, width: usize) -> u64 {
+                    if width == 0 {
+                        return 0;
+                    }
+                    ((self.get() as u128 >> start) as u64) & low_mask(width)
+                }
+
+                #[inline]
+                fn set_bits(&self, start: usize, width: usize, value: u64) {
+                    if width == 0 {
+                        return;
+                    }
+                    let mask = (low_mask(width) as u128) << start;
+                    let value = ((value & low_mask(width)) as u128) << start;
+                    self.set(((self.get() as u128 & !mask) | value) as $int);
+                }
+            }
+
+            impl AsBitStorage for $int {
+                type Storage = Cell<$int>;
+
+                #[inline(always)]
+                fn _11748397628858797803_as_bit_storage(&mut self) -> &Cell<$int> {
+                    Cell::from_mut(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_int_storage! { u8 u16 u32 u64 u128 usize }
+
+impl Sealed for [u8] {}
+impl Sealed for [Cell<u8>] {}
+
+impl BitStorage for [Cell<u8>] {
+    #[inline]
+    fn bit_len(&self) -> usize {
+        self.len().saturating_mul(8)
+    }
+
+    fn get_bits(&self, start: usize, width: usize) -> u64 {
+        let mut value = 0u64;
+        let mut done = 0;
+        while done < width {
+            let bit = start + done;
+            let offset = bit % 8;
+            let taken = core::cmp::min(8 - offset, width - done);
+            let bits = (self[bit / 8].get() >> offset) as u64 & low_mask(taken);
+            value |= bits << done;
+            done += taken;
+        }
+        value
+    }
+
+    fn set_bits(&self, start: usize, width: usize, value: u64) {
+        let mut done = 0;
+        while done < width {
+            let bit = start + done;
+            let offset = bit % 8;
+            let taken = core::cmp::min(8 - offset, width - done);
+            let mask = (low_mask(taken) as u8) << offset;
+            let bits = ((value >> done) & low_mask(taken)) as u8;
+            let byte = &self[bit / 8];
+            byte.set((byte.get() & !mask) | (bits << offset));
+            done += taken;
+        }
+    }
+}
+
+impl AsBitStorage for [u8] {
+    type Storage = [Cell<u8>];
+
+    #[inline(always)]
+    fn _11748397628858797803_as_bit_storage(&mut self) -> &[Cell<u8>] {
+        Cell::from_mut(self).as_slice_of_cells()
+    }
+}
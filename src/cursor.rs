@@ -0,0 +1,257 @@
+//! Cursors for reading a slice in parts, with the [`take`] and [`peek`] macros,
+//! and for writing a slice in parts, with the [`put`] macro.
+//!
+//! [`take`]: ../macro.take.html
+//! [`peek`]: ../macro.peek.html
+//! [`put`]: ../macro.put.html
+
+use crate::index_argument::IndexArgument;
+
+use core::fmt::{self, Debug};
+
+/// A cursor that advances through a slice,
+/// for parsing it with the [`take`] macro.
+///
+/// The arguments of the [`take`] and [`peek`] macros are relative
+/// to the current position of the cursor.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::{peek, take, Cursor};
+///
+/// let bytes = [0, 0, 1, 10, 20, 3, 4, 5, 6];
+/// let mut cursor = Cursor::new(&bytes[..]);
+///
+/// let (len, kind) = take!(cursor; u32be @ ..4, 4).unwrap();
+/// assert_eq!((len, kind), (266, &20));
+/// assert_eq!(cursor.position(), 5);
+///
+/// // The cursor isn't advanced if the arguments are out of bounds.
+/// assert_eq!(take!(cursor; ..5), None);
+/// assert_eq!(cursor.position(), 5);
+///
+/// assert_eq!(peek!(cursor; 1), Some((&4,)));
+/// assert_eq!(cursor.skip(2), Some(&[3, 4][..]));
+///
+/// // Trailing unbounded ranges consume the rest of the slice.
+/// assert_eq!(take!(cursor; 0, ..), Some((&5, &[6][..])));
+/// assert!(cursor.is_empty());
+///
+/// ```
+///
+/// [`take`]: ../macro.take.html
+/// [`peek`]: ../macro.peek.html
+pub struct Cursor<'a, T> {
+    rest: &'a [T],
+    position: usize,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Constructs a cursor at the start of `slice`.
+    #[inline]
+    pub const fn new(slice: &'a [T]) -> Self {
+        Self {
+            rest: slice,
+            position: 0,
+        }
+    }
+
+    /// The elements after the current position.
+    #[inline]
+    pub const fn rest(&self) -> &'a [T] {
+        self.rest
+    }
+
+    /// How many elements have been consumed.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// How many elements remain.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.rest.len()
+    }
+
+    /// Whether there are no remaining elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// Advances past the next `n` elements, returning them.
+    ///
+    /// This returns `None`, without advancing, if there are fewer than `n` elements left.
+    #[inline]
+    pub fn skip(&mut self, n: usize) -> Option<&'a [T]> {
+        if n > self.rest.len() {
+            return None;
+        }
+        let skipped = &self.rest[..n];
+        self.advance(n);
+        Some(skipped)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn advance(&mut self, n: usize) {
+        self.rest = &self.rest[n..];
+        self.position += n;
+    }
+}
+
+impl<T> Copy for Cursor<'_, T> {}
+
+impl<T> Clone for Cursor<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Default for Cursor<'_, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl<T: Debug> Debug for Cursor<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cursor")
+            .field("position", &self.position)
+            .field("rest", &self.rest)
+            .finish()
+    }
+}
+
+/// A cursor that advances through a mutable slice,
+/// for writing into it with the [`put`] macro.
+///
+/// The arguments of the [`put`] macro are relative
+/// to the current position of the cursor.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::{put, WriteCursor};
+///
+/// let mut packet = [0u8; 10];
+/// let mut cursor = WriteCursor::new(&mut packet[..]);
+///
+/// let (magic, version, len) = (*b"MAGI", 2, 3u16);
+/// put!(cursor; 0..4 = magic, 4 = version, 6..8 = len.to_be_bytes()).unwrap();
+/// assert_eq!(cursor.position(), 8);
+///
+/// // The cursor isn't advanced, nor written to, if the arguments are out of bounds.
+/// assert_eq!(put!(cursor; 0 = 1, 2 = 3), None);
+///
+/// put!(cursor; u16le @ ..2 = 0xABCD).unwrap();
+/// assert!(cursor.is_empty());
+///
+/// assert_eq!(packet, *b"MAGI\x02\x00\x00\x03\xCD\xAB");
+///
+/// ```
+///
+/// [`put`]: ../macro.put.html
+pub struct WriteCursor<'a, T> {
+    rest: &'a mut [T],
+    position: usize,
+}
+
+impl<'a, T> WriteCursor<'a, T> {
+    /// Constructs a cursor at the start of `slice`.
+    #[inline]
+    pub fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            rest: slice,
+            position: 0,
+        }
+    }
+
+    /// The elements after the current position.
+    #[inline]
+    pub fn rest(&self) -> &[T] {
+        self.rest
+    }
+
+    /// The elements after the current position, mutably.
+    #[inline]
+    pub fn rest_mut(&mut self) -> &mut [T] {
+        self.rest
+    }
+
+    /// Converts this into the elements after the current position.
+    #[inline]
+    pub fn into_rest(self) -> &'a mut [T] {
+        self.rest
+    }
+
+    /// How many elements have been written (or skipped).
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// How many elements remain.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.rest.len()
+    }
+
+    /// Whether there are no remaining elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// Advances past the next `n` elements, returning them.
+    ///
+    /// This returns `None`, without advancing, if there are fewer than `n` elements left.
+    #[inline]
+    pub fn skip(&mut self, n: usize) -> Option<&'a mut [T]> {
+        if n > self.rest.len() {
+            return None;
+        }
+        let (skipped, rest) = core::mem::take(&mut self.rest).split_at_mut(n);
+        self.rest = rest;
+        self.position += n;
+        Some(skipped)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn advance(&mut self, n: usize) {
+        self.rest = &mut core::mem::take(&mut self.rest)[n..];
+        self.position += n;
+    }
+}
+
+impl<T> Default for WriteCursor<'_, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(&mut [])
+    }
+}
+
+impl<T: Debug> Debug for WriteCursor<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WriteCursor")
+            .field("position", &self.position)
+            .field("rest", &self.rest)
+            .finish()
+    }
+}
+
+/// The amount of elements up to the end of the furthest argument,
+/// where trailing unbounded ranges end at the end of the slice.
+#[doc(hidden)]
+pub fn consumed_len(ind_args: &[IndexArgument], slice_len: usize) -> usize {
+    ind_args
+        .iter()
+        .map(|ind_arg| ind_arg.end().unwrap_or(slice_len))
+        .max()
+        .unwrap_or(0)
+}
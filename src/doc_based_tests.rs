@@ -89,9 +89,342 @@ pub struct InclusiveUptoUsizeMaxError;
 /// multindex::multindex_mut!(arr; 1, 1 );
 /// ```
 ///
+/// ```compile_fail
+/// let mut arr = [(); 100];
+/// multindex::multiget_each_mut!(arr; 0..4, 150..160, 3 );
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [(); 100];
+/// multindex::multiget_clamped_mut!(arr; 0..4, 150..160, 3 );
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [(); 100];
+/// multindex::multindex_mut!(arr; ..8, 8.., end - 4.., 9 );
+/// ```
+///
 /// ```rust
 /// let mut arr = [(); 100];
 /// multindex::multindex_mut!(arr; 1, 2 );
+/// multindex::multiget_each!(arr; 0..4, 150..160, 3 );
+/// multindex::multiget_each_mut!(arr; 0..3, 150..160, 3 );
+/// multindex::multiget_clamped!(arr; 0..4, 150..160, 3 );
+/// multindex::multiget_clamped_mut!(arr; 0..3, 150..160, 3 );
+/// multindex::multindex_mut!(arr; ..8, 9, 10.., end - 4.. );
 /// ```
 ///
 pub struct OverlappingIndexArgsError;
+
+///
+/// ```compile_fail
+/// let mut arr = [(); 100];
+/// let i = 5;
+/// multindex::multindex_mut!(arr; 1, dyn i, 1 );
+/// ```
+///
+/// ```rust
+/// let mut arr = [(); 100];
+/// let i = 5;
+/// multindex::multindex_mut!(arr; 1, dyn i, 2 );
+/// ```
+///
+pub struct OverlappingConstArgsWithDynError;
+
+///
+/// ```compile_fail
+/// let mut arr = [(); 100];
+/// let i = 5;
+/// multindex::multindex_mut!(arr; 1.., dyn i);
+/// ```
+///
+/// ```rust
+/// let mut arr = [(); 100];
+/// let i = 5;
+/// multindex::multindex_mut!(arr; 1..3, dyn i);
+/// ```
+///
+pub struct UnboundedNextToDynError;
+
+///
+/// ```compile_fail
+/// let mut arr = [(); 100];
+/// multindex::multindex_mut!(arr; 0, end - 4.., end - 1);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [(); 100];
+/// multindex::multindex_mut!(arr; end - 2, 5..);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [(); 100];
+/// multindex::multindex_mut!(arr; 0.., end - 2, end - 4);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [(); 100];
+/// multindex::try_multindex_mut!(arr; end - 4..end - 2, end - 3);
+/// ```
+///
+/// ```rust
+/// let mut arr = [(); 100];
+/// multindex::multindex!(arr; 0, end - 4.., end - 1);
+/// multindex::multindex_mut!(arr; 0, end - 4..end - 1, end - 1);
+/// multindex::multindex_mut!(arr; 5.., end - 2);
+/// multindex::multindex_mut!(arr; 0.., end - 4, end - 2..);
+/// multindex::try_multindex_mut!(arr; end - 4..end - 2, end - 2);
+/// ```
+///
+pub struct OverlappingFromEndArgsError;
+
+///
+/// ```compile_fail
+/// let arr = [0u8; 100];
+/// multindex::multindex!(arr; 0, u32be @ 1..4 );
+/// ```
+///
+/// ```compile_fail
+/// let arr = [0u8; 100];
+/// multindex::multindex!(arr; 0, u32be @ 1.. );
+/// ```
+///
+/// ```compile_fail
+/// let arr = [0u16; 100];
+/// multindex::multindex!(arr; 0, u32be @ 1..5 );
+/// ```
+///
+/// ```rust
+/// let arr = [0u8; 100];
+/// multindex::multindex!(arr; 0, u32be @ 1..5 );
+/// ```
+///
+pub struct TypedArgumentLengthError;
+
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multiswap!(arr; 0..4, 8..11);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multiswap!(arr; 0, 8..9);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multiswap!(arr; ..4, 8..);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multicopy_within!(arr; 0 => 1, 2..4 => 6..9);
+/// ```
+///
+/// ```rust
+/// let mut arr = [0u8; 100];
+/// multindex::multiswap!(arr; ..4, 8..12);
+/// multindex::multicopy_within!(arr; 0 => 1, 2..=4 => 6..9);
+/// ```
+///
+pub struct MismatchedLengthsError;
+
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::permute!(arr; [1, 2, 0, 2]);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::permute!(arr; [1, 2, 3]);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::permute!(arr; [1, 0..1]);
+/// ```
+///
+/// ```rust
+/// let mut arr = [0u8; 100];
+/// multindex::permute!(arr; [1, 2, 0]);
+/// ```
+///
+pub struct NotAPermutationError;
+
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multindex_exhaustive_mut!(arr; 1, 2..4);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multindex_exhaustive_mut!(arr; 0, 2..4);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multindex_exhaustive_mut!(arr; 2..4, 0..2);
+/// ```
+///
+/// ```rust
+/// let mut arr = [0u8; 100];
+/// multindex::multindex_exhaustive_mut!(arr; 0, 1..4, ..8, 8..=9, 10.., );
+/// multindex::multindex_exhaustive_mut!(arr; ..0, 0..4, 4..4, 4);
+/// ```
+///
+pub struct GapBeforeArgumentError;
+
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// let i = 0;
+/// multindex::multindex_exhaustive_mut!(arr; dyn i, 1..);
+/// ```
+///
+/// ```compile_fail
+/// let arr = [0u8; 100];
+/// let i = 0;
+/// multindex::multiget_each!(arr; 0..2, 2, dyn i);
+/// ```
+///
+/// ```compile_fail
+/// let arr = [0u8; 100];
+/// let i = 0;
+/// multindex::multiget_clamped!(arr; 0..2, dyn i);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multindex_rest_mut!(arr; 0..2, end - 4..);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multiswap!(arr; 0..4, end - 4..);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// let (i, j, k, l) = (0, 4, 8, 12);
+/// multindex::multiswap!(arr; dyn i..j, dyn k..l);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// let i = 0;
+/// multindex::multirotate!(arr; 0..2, dyn i..i + 2, 4..6);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multicopy_within!(arr; 0..2 => end - 2..);
+/// ```
+///
+/// ```compile_fail
+/// let arr = [0u8; 100];
+/// let i = 0;
+/// multindex::try_multindex!(arr; 0..2, dyn i);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// let i = 0;
+/// multindex::try_multindex_mut!(arr; 0..2, dyn i, end - 2);
+/// ```
+///
+/// ```rust
+/// let mut arr = [0u8; 100];
+/// let i = 0;
+/// multindex::multindex_mut!(arr; dyn i, 1..);
+/// multindex::multindex_exhaustive_mut!(arr; 0, 1..);
+/// multindex::multiget_each!(arr; 0..2, 2, 3);
+/// multindex::multiget_clamped!(arr; 0..2, 2);
+/// multindex::multindex_mut!(arr; 0..2, end - 4..);
+/// multindex::try_multindex_mut!(arr; 0..2, end - 4..).unwrap();
+/// multindex::multiswap!(arr; 0..4, 96..100);
+/// ```
+///
+pub struct RuntimeArgumentError;
+
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::bitindex!(arr; 0..65);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::bitindex!(arr; 0, 1..66, 66..);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::bitindex!(arr; 10.., 80);
+/// ```
+///
+/// ```rust
+/// let mut arr = [0u8; 100];
+/// multindex::bitindex!(arr; 0..64);
+/// multindex::bitindex!(arr; 0, 1..65, 65..129);
+/// multindex::bitindex!(arr; 736..);
+/// ```
+///
+pub struct FieldTooWideError;
+
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multindex_mixed!(arr; mut 0..4, ref 2);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multindex_mixed!(arr; ref 0..4, mut 2);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multindex_mixed!(arr; mut 0..4, mut 3..);
+/// ```
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 100];
+/// multindex::multindex_mixed!(arr; 0..4, ref 5);
+/// ```
+///
+/// ```rust
+/// let mut arr = [0u8; 100];
+/// multindex::multindex_mixed!(arr; ref 0..4, ref 2, mut 4, ref 3..4);
+/// ```
+///
+pub struct MixedMutabilityOverlapError;
+
+///
+/// ```compile_fail
+/// #[derive(Copy, Clone)]
+/// struct NotPod(u32);
+///
+/// let arr = [0u8; 100];
+/// multindex::multindex!(arr; cast<NotPod> @ 0..4 );
+/// ```
+///
+/// ```compile_fail
+/// let arr = [0u8; 100];
+/// multindex::multindex!(arr; cast<u32> @ 0..8 );
+/// ```
+///
+/// ```compile_fail
+/// let arr = [0u32; 100];
+/// multindex::multindex!(arr; cast<u32> @ 0..4 );
+/// ```
+///
+/// ```rust
+/// let arr = [0u32; 100];
+/// let bytes = unsafe { core::slice::from_raw_parts(arr.as_ptr() as *const u8, 400) };
+/// multindex::multindex!(bytes; cast<u32> @ 0..4 );
+/// ```
+///
+pub struct CastArgumentError;
@@ -0,0 +1,309 @@
+use crate::{
+    are_disjoint::AreAllDisjoint,
+    error::{Error, OutOfBounds},
+    index_argument::{
+        IK_Index, IK_Range, IK_RangeFrom, IndexArgument, IndexFromEnd, IndexKind, IntoPrenormIndex,
+        PrenormIndex, RangeFromEnd,
+    },
+    index_properties::{IndexArgumentStats, IndexProperties},
+    ptr_indexing::{Indexer, IndexerParams},
+};
+
+use core::{
+    marker::PhantomData,
+    ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+};
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// An index/range argument whose value is only known at runtime.
+///
+/// `IK` is the type-level `IndexKind` used to index the slice,
+/// `IK_Index` for integers, and `IK_RangeFrom` for ranges
+/// (which get returned as slices, since their length isn't known at compile-time).
+pub struct DynIndexArg<IK> {
+    pub prenorm: PrenormIndex,
+    _kind: PhantomData<fn() -> IK>,
+}
+
+impl<IK> Copy for DynIndexArg<IK> {}
+
+impl<IK> Clone for DynIndexArg<IK> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<IK> DynIndexArg<IK> {
+    #[inline(always)]
+    pub fn new<A>(arg: A) -> Self
+    where
+        A: IntoDynIndexArg<Kind = IK>,
+    {
+        Self {
+            prenorm: arg.into_prenorm(),
+            _kind: PhantomData,
+        }
+    }
+
+    /// Constructs the `Indexer` for this argument.
+    ///
+    /// `ind_arg` must be the normalized version of this argument,
+    /// which must be in bounds for a slice of length `slice_len`.
+    #[inline(always)]
+    pub fn indexer<T>(self, ind_arg: &IndexArgument, slice_len: usize) -> Indexer<T, (), IK> {
+        IndexerParams {
+            index: ind_arg.start as _,
+            // The IK_RangeFrom Indexer returns a slice up to `slice_len`,
+            // this is how bounded ranges are indexed.
+            slice_len: option_unwrap_or_else!(ind_arg.end(), slice_len),
+        }
+        .build()
+    }
+}
+
+/// The types that can be used as runtime index/range arguments.
+pub trait IntoDynIndexArg {
+    /// The type-level `IndexKind` that this type is indexed with.
+    type Kind;
+
+    fn into_prenorm(self) -> PrenormIndex;
+}
+
+macro_rules! impl_into_dyn_index_arg {
+    ($($ty:ty => $kind:ty,)*) => (
+        $(
+            impl IntoDynIndexArg for $ty {
+                type Kind = $kind;
+
+                #[inline(always)]
+                fn into_prenorm(self) -> PrenormIndex {
+                    IntoPrenormIndex(self).call()
+                }
+            }
+        )*
+    )
+}
+
+impl_into_dyn_index_arg! {
+    usize => IK_Index,
+    Range<usize> => IK_RangeFrom,
+    RangeFull => IK_RangeFrom,
+    RangeFrom<usize> => IK_RangeFrom,
+    RangeTo<usize> => IK_RangeFrom,
+    RangeInclusive<usize> => IK_RangeFrom,
+    RangeToInclusive<usize> => IK_RangeFrom,
+    IndexFromEnd => IK_Index,
+    RangeFromEnd => IK_Range,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The runtime equivalent of what the compile-time indexing macros compute in constants.
+///
+/// This normalizes `prenorm` into `ind_args`,
+/// checks that the arguments don't overlap (if `expected_are_disjoint` is `YES`),
+/// and checks that the arguments are in bounds for a slice of length `slice_len`.
+pub fn compute_dyn_index_arguments(
+    prenorm: &[PrenormIndex],
+    ind_args: &mut [IndexArgument],
+    slice_len: usize,
+    expected_are_disjoint: AreAllDisjoint,
+) -> Result<(), Error> {
+    let iaas = new_IndexArgumentsAndStats! {
+        @initialize
+        prenorm = prenorm,
+        ind_args = ind_args,
+        error_handling(|e| return Err(e) ),
+    };
+
+    IndexProperties::new(iaas.ind_args, &iaas.stats, expected_are_disjoint)
+        .are_disjoint
+        .check_is_expected(&expected_are_disjoint)?;
+
+    if iaas.stats.max_bounded_end > slice_len {
+        return Err(Error::OutOfBounds(OutOfBounds {
+            slice_len,
+            max_bounded_end: iaas.stats.max_bounded_end,
+            argument: iaas.stats.max_bounded_end_arg,
+        }));
+    }
+
+    Ok(())
+}
+
+/// Normalizes and checks the runtime arguments of the compile-time indexing macros.
+///
+/// `ind_args` are the arguments normalized at compile-time,
+/// with placeholders at the positions of the runtime arguments.
+///
+/// `dyn_args` are the positions and values of the runtime arguments,
+/// each one is normalized as though it was the only argument.
+/// This includes the arguments relative to the end of the slice (eg: `end - 4..`),
+/// which are normalized after the `dyn` arguments,
+/// where a range with an unbounded end before one of them is made to end where it starts.
+///
+/// This checks that the runtime arguments are in bounds
+/// for a slice of length `slice_len`,
+/// and that they don't overlap any other argument
+/// (if `expected_are_disjoint` is `YES`, or `OnlyMut` for the `mut` arguments).
+///
+/// An argument relative to the end of the slice that starts before the end of
+/// the arguments that it can't overlap is reported as `OutOfBounds`,
+/// with the length that the slice needs for it to start after all of them.
+pub fn compute_mixed_index_arguments(
+    ind_args: &mut [IndexArgument],
+    dyn_args: &[(u16, PrenormIndex)],
+    slice_len: usize,
+    expected_are_disjoint: AreAllDisjoint,
+) -> Result<(), Error> {
+    let is_from_end = |i: usize| {
+        dyn_args
+            .iter()
+            .any(|&(index, prenorm)| index as usize == i && prenorm.is_from_end())
+    };
+
+    // The length that the slice needs for the argument relative to its end at
+    // `current_index`, to start after the arguments that it can't overlap,
+    // and after the range with an unbounded end right before it.
+    let from_end_required_len = |ind_args: &[IndexArgument], current_index: u16, from_end| {
+        let current_index = current_index as usize;
+        let max_end = ind_args
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| {
+                i != current_index
+                    && !is_from_end(i)
+                    && (expected_are_disjoint.must_be_disjoint(i, current_index)
+                        || i + 1 == current_index
+                            && matches!(ind_args[i].index_kind(), IndexKind::RangeFrom))
+            })
+            .map(|(_, other)| other.bounded_end())
+            .max()
+            .unwrap_or(0);
+        max_end.saturating_add(from_end)
+    };
+
+    let dyn_first = dyn_args
+        .iter()
+        .filter(|(_, prenorm)| !prenorm.is_from_end());
+    let from_end_last = dyn_args.iter().filter(|(_, prenorm)| prenorm.is_from_end());
+
+    for &(current_index, prenorm) in dyn_first.chain(from_end_last) {
+        let resolved = match prenorm.resolve_from_end(slice_len, current_index) {
+            Ok(x) => x,
+            Err(Error::OutOfBounds(oob)) => {
+                return Err(Error::OutOfBounds(OutOfBounds {
+                    max_bounded_end: from_end_required_len(
+                        ind_args,
+                        current_index,
+                        oob.max_bounded_end,
+                    ),
+                    ..oob
+                }))
+            }
+            Err(e) => return Err(e),
+        };
+        let (ind_arg, stats) =
+            match IndexArgument::from_prenorm(&[resolved], 0, IndexArgumentStats::NEW) {
+                Ok(x) => x,
+                Err(Error::InclusiveUptoUsizeMax { .. }) => {
+                    return Err(Error::InclusiveUptoUsizeMax { current_index })
+                }
+                Err(e) => return Err(e),
+            };
+
+        if stats.max_bounded_end > slice_len {
+            return Err(Error::OutOfBounds(OutOfBounds {
+                slice_len,
+                max_bounded_end: stats.max_bounded_end,
+                argument: current_index,
+            }));
+        }
+
+        ind_args[current_index as usize] = ind_arg;
+
+        if prenorm.is_from_end() && current_index != 0 {
+            let prev_index = current_index as usize - 1;
+            let prev = ind_args[prev_index];
+            if let IndexKind::RangeFrom = prev.index_kind() {
+                ind_args[prev_index] = match prev.ending_at(ind_arg.start) {
+                    Some(x) => x,
+                    None => {
+                        return Err(Error::OutOfBounds(OutOfBounds {
+                            slice_len,
+                            max_bounded_end: from_end_required_len(
+                                ind_args,
+                                current_index,
+                                slice_len - ind_arg.start,
+                            ),
+                            argument: current_index,
+                        }))
+                    }
+                };
+            }
+        }
+    }
+
+    if !matches!(expected_are_disjoint, AreAllDisjoint::No(_)) {
+        for &(current_index, prenorm) in dyn_args {
+            let dyn_arg = &ind_args[current_index as usize];
+
+            for (i, other) in ind_args.iter().enumerate() {
+                if i != current_index as usize
+                    && expected_are_disjoint.must_be_disjoint(i, current_index as usize)
+                    && dyn_arg.intersects(other)
+                {
+                    // The overlaps between an argument relative to the end of the slice
+                    // and a bounded argument relative to its start go away with a longer slice,
+                    // `IndexArgument::check_from_end_disjoint` rejects the other ones
+                    // at compile-time.
+                    let from_end_index = if prenorm.is_from_end() {
+                        Some(current_index)
+                    } else if is_from_end(i) {
+                        Some(i as u16)
+                    } else {
+                        None
+                    };
+                    let bounded = |x: &IndexArgument| x.end().is_some();
+                    if let Some(from_end_index) = from_end_index {
+                        let from_end_arg = &ind_args[from_end_index as usize];
+                        if bounded(dyn_arg) && bounded(other) {
+                            return Err(Error::OutOfBounds(OutOfBounds {
+                                slice_len,
+                                max_bounded_end: from_end_required_len(
+                                    ind_args,
+                                    from_end_index,
+                                    slice_len - from_end_arg.start,
+                                ),
+                                argument: from_end_index,
+                            }));
+                        }
+                    }
+
+                    let (left, right) = if i < current_index as usize {
+                        (i as u16, current_index)
+                    } else {
+                        (current_index, i as u16)
+                    };
+                    return Err(Error::OverlappingIndexArgs { left, right });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Converts the error from the runtime arguments of the `try_*` macros to `OutOfBounds`.
+///
+/// The `try_*` macros reject `dyn` arguments at compile-time,
+/// so the arguments relative to the end of the slice can only error with `OutOfBounds`,
+/// this panics for any other error.
+#[inline]
+pub fn dyn_error_to_out_of_bounds(error: Error) -> OutOfBounds {
+    match error {
+        Error::OutOfBounds(x) => x,
+        _ => crate::utils::panic_on_dyn_error(error),
+    }
+}
@@ -0,0 +1,165 @@
+//! Byte arrays that represent integers and floats with a specific byte order,
+//! which the indexing macros take as typed arguments.
+//!
+//! A typed argument is one of the types in this module,
+//! followed by `@` and a range as long as that type,
+//! eg: `u32be @ 0..4`, `i16le @ 4..6`.
+//!
+//! The shared indexing macros (eg: [`multindex`]) return the decoded value
+//! for typed arguments,
+//! while the mutable ones (eg: [`multindex_mut`]) return a mutable reference to
+//! the type in this module, which has `get` and `set` methods.
+//!
+//! The types don't need to be imported to be used in the indexing macros.
+//!
+//! It's a compile-time error for the range to have a different length than the type,
+//! or for the indexed slice to not be a `[u8]`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use multindex::{multiget, multindex_mut};
+//!
+//! let mut packet = [0x12, 0x34, 0xFE, 0xFF, 1, 2, 3];
+//!
+//! let (kind, offset, rest) = multiget!(packet; u16be @ 0..2, i16le @ 2..4, ..).unwrap();
+//! assert_eq!(kind, 0x1234);
+//! assert_eq!(offset, -2);
+//! assert_eq!(rest, &[1, 2, 3][..]);
+//!
+//! let (kind, offset) = multindex_mut!(packet; u16be @ 0..2, i16le @ 2..4);
+//! kind.set(kind.get() + 1);
+//! offset.set(300);
+//!
+//! assert_eq!(packet, [0x12, 0x35, 0x2C, 0x01, 1, 2, 3]);
+//!
+//! ```
+//!
+//! This doesn't compile, because the range is 3 elements long,
+//! and a `u32be` is 4 bytes long.
+//!
+//! ```compile_fail
+//! let packet = [0u8; 8];
+//! let _ = multindex::multindex!(packet; u32be @ 0..3);
+//! ```
+//!
+//! [`multindex`]: ../macro.multindex.html
+//! [`multindex_mut`]: ../macro.multindex_mut.html
+
+use crate::writing::Put;
+
+use core::fmt::{self, Debug};
+
+macro_rules! declare_endian_types {
+    (
+        $((
+            $name:ident,
+            $value:ident,
+            $size:expr,
+            $from_bytes:ident,
+            $to_bytes:ident,
+            $doc:literal
+        ))*
+    ) => {
+        $(
+            #[doc = $doc]
+            ///
+            /// This has the same size and alignment as its array of bytes.
+            #[repr(transparent)]
+            #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+            pub struct $name([u8; $size]);
+
+            impl $name {
+                /// The amount of bytes in this type.
+                pub const SIZE: usize = $size;
+
+                /// Encodes `value` as bytes.
+                #[inline]
+                pub fn new(value: $value) -> Self {
+                    Self(value.$to_bytes())
+                }
+
+                /// Constructs this from its bytes.
+                #[inline]
+                pub const fn from_bytes(bytes: [u8; $size]) -> Self {
+                    Self(bytes)
+                }
+
+                /// Converts a reference to bytes into a reference to this type.
+                #[inline]
+                pub fn from_bytes_ref(bytes: &[u8; $size]) -> &Self {
+                    // Safety: this is a `#[repr(transparent)]` wrapper around `[u8; $size]`
+                    unsafe { &*(bytes as *const [u8; $size] as *const Self) }
+                }
+
+                /// Converts a mutable reference to bytes into a mutable reference to this type.
+                #[inline]
+                pub fn from_bytes_mut(bytes: &mut [u8; $size]) -> &mut Self {
+                    // Safety: this is a `#[repr(transparent)]` wrapper around `[u8; $size]`
+                    unsafe { &mut *(bytes as *mut [u8; $size] as *mut Self) }
+                }
+
+                /// Gets the bytes of this type.
+                #[inline]
+                pub const fn to_bytes(self) -> [u8; $size] {
+                    self.0
+                }
+
+                /// Decodes the value.
+                #[inline]
+                pub fn get(&self) -> $value {
+                    $value::$from_bytes(self.0)
+                }
+
+                /// Encodes `value`, overwriting the previous one.
+                #[inline]
+                pub fn set(&mut self, value: $value) {
+                    self.0 = value.$to_bytes();
+                }
+            }
+
+            impl Debug for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_tuple(stringify!($name)).field(&self.get()).finish()
+                }
+            }
+
+            impl Put<$value> for $name {
+                #[inline(always)]
+                fn put(&mut self, value: $value) {
+                    self.set(value);
+                }
+            }
+
+            impl From<$value> for $name {
+                #[inline]
+                fn from(value: $value) -> Self {
+                    Self::new(value)
+                }
+            }
+        )*
+    };
+}
+
+declare_endian_types! {
+    (u16be, u16, 2, from_be_bytes, to_be_bytes, "A `u16` stored as big-endian bytes.")
+    (u16le, u16, 2, from_le_bytes, to_le_bytes, "A `u16` stored as little-endian bytes.")
+    (u32be, u32, 4, from_be_bytes, to_be_bytes, "A `u32` stored as big-endian bytes.")
+    (u32le, u32, 4, from_le_bytes, to_le_bytes, "A `u32` stored as little-endian bytes.")
+    (u64be, u64, 8, from_be_bytes, to_be_bytes, "A `u64` stored as big-endian bytes.")
+    (u64le, u64, 8, from_le_bytes, to_le_bytes, "A `u64` stored as little-endian bytes.")
+    (u128be, u128, 16, from_be_bytes, to_be_bytes, "A `u128` stored as big-endian bytes.")
+    (u128le, u128, 16, from_le_bytes, to_le_bytes, "A `u128` stored as little-endian bytes.")
+    (i16be, i16, 2, from_be_bytes, to_be_bytes, "An `i16` stored as big-endian bytes.")
+    (i16le, i16, 2, from_le_bytes, to_le_bytes, "An `i16` stored as little-endian bytes.")
+    (i32be, i32, 4, from_be_bytes, to_be_bytes, "An `i32` stored as big-endian bytes.")
+    (i32le, i32, 4, from_le_bytes, to_le_bytes, "An `i32` stored as little-endian bytes.")
+    (i64be, i64, 8, from_be_bytes, to_be_bytes, "An `i64` stored as big-endian bytes.")
+    (i64le, i64, 8, from_le_bytes, to_le_bytes, "An `i64` stored as little-endian bytes.")
+    (i128be, i128, 16, from_be_bytes, to_be_bytes, "An `i128` stored as big-endian bytes.")
+    (i128le, i128, 16, from_le_bytes, to_le_bytes, "An `i128` stored as little-endian bytes.")
+    (f32be, f32, 4, from_be_bytes, to_be_bytes, "A `f32` stored as big-endian bytes.")
+    (f32le, f32, 4, from_le_bytes, to_le_bytes, "A `f32` stored as little-endian bytes.")
+    (f64be, f64, 8, from_be_bytes, to_be_bytes, "A `f64` stored as big-endian bytes.")
+    (f64le, f64, 8, from_le_bytes, to_le_bytes, "A `f64` stored as little-endian bytes.")
+}
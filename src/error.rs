@@ -1,26 +1,192 @@
-use core::marker::PhantomData;
+use crate::{
+    bits::MAX_FIELD_BITS,
+    index_argument::{IndexArgument, PrenormIndex},
+};
 
+use core::{fmt, marker::PhantomData};
+
+/// The errors that the indexing macros can produce.
+///
+/// The compile-time indexing macros report these as type errors,
+/// the runtime indexing macros (eg: [`multindex_dyn_mut`]) return them.
+///
+/// The `current_index`, `left`, and `right` fields are
+/// positions of arguments in the macro invocation, starting at 0.
+///
+/// This enum is `#[non_exhaustive]`, so that errors can be added for new macros
+/// without breaking code that matches on it.
+///
+/// [`multindex_dyn_mut`]: ../macro.multindex_dyn_mut.html
+/// [`multiswap`]: ../macro.multiswap.html
+/// [`multindex_exhaustive_mut`]: ../macro.multindex_exhaustive_mut.html
+/// [`multindex_rest_mut`]: ../macro.multindex_rest_mut.html
+/// [`permute`]: ../macro.permute.html
+/// [`bitindex`]: ../macro.bitindex.html
+/// [`MAX_FIELD_BITS`]: ../bits/constant.MAX_FIELD_BITS.html
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[non_exhaustive]
 pub enum Error {
-    PrevEndIsUnbounded {
-        current_index: u16,
-    },
-    NextStartIsUnbounded {
+    /// When a range argument with an unbounded start is preceded by a
+    /// range argument with an unbounded end.
+    PrevEndIsUnbounded { current_index: u16 },
+    /// When a range argument with an unbounded end is followed by a
+    /// range argument with an unbounded start.
+    NextStartIsUnbounded { current_index: u16 },
+    /// When a range argument with an unbounded end is followed by an
+    /// argument that compares less to it.
+    NextStartIsLessThanCurrent { current_index: u16 },
+    /// When an index argument was a `n ..= usize::MAX` range,
+    /// or a `usize::MAX` index.
+    InclusiveUptoUsizeMax { current_index: u16 },
+    /// When two arguments overlap, in macros that require them not to.
+    OverlappingIndexArgs { left: u16, right: u16 },
+    /// When a typed argument (eg: `u32be @ 0..4`) isn't a range
+    /// as long as the size of its type.
+    TypedArgumentLength {
         current_index: u16,
+        type_size: usize,
     },
-    NextStartIsLessThanCurrent {
+    /// When two arguments that must be the same length (eg: in [`multiswap`])
+    /// aren't both indices, or bounded ranges of the same length.
+    MismatchedLengths { left: u16, right: u16 },
+    /// When an argument of [`permute`] isn't an index less than
+    /// the amount of arguments (`len`).
+    NotAPermutation { current_index: u16, len: u16 },
+    /// When an argument of an exhaustive macro (eg: [`multindex_exhaustive_mut`])
+    /// doesn't start where the previous one ends (or at 0, for the first argument),
+    /// leaving a gap before it.
+    GapBeforeArgument {
         current_index: u16,
+        expected_start: usize,
     },
-    /// When an index argument was a `n ..= usize::MAX` range.
-    InclusiveUptoUsizeMax {
-        current_index: u16,
-    },
-    OverlappingIndexArgs {
-        left: u16,
-        right: u16,
-    },
+    /// When a runtime (`dyn`) argument is passed to a macro that
+    /// needs to know every argument at compile-time (eg: [`multindex_rest_mut`]).
+    RuntimeArgument { current_index: u16 },
+    /// When a bounded argument of [`bitindex`] is more than
+    /// [`MAX_FIELD_BITS`] bits long (its `width`).
+    FieldTooWide { current_index: u16, width: usize },
+    /// When the arguments are out of bounds for the indexed slice.
+    ///
+    /// This is only returned by the macros that take runtime arguments.
+    OutOfBounds(OutOfBounds),
+}
+
+/// The error returned by the fallible indexing macros,
+/// when the indices/ranges are out of bounds for the indexed slice.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::{try_multindex, OutOfBounds};
+///
+/// let arr = [3u8, 5, 8, 13, 21];
+///
+/// let err = try_multindex!(arr; 0, 2..4, 3..8, 6).unwrap_err();
+/// assert_eq!(err.slice_len, 5);
+/// assert_eq!(err.max_bounded_end, 8);
+/// assert_eq!(err.argument, 2);
+///
+/// assert_eq!(
+///     err.to_string(),
+///     "argument 2 requires a slice of at least 8 elements, but the slice has 5 elements",
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct OutOfBounds {
+    /// The length of the indexed slice.
+    pub slice_len: usize,
+    /// The minimum length that the slice needed to be.
+    pub max_bounded_end: usize,
+    /// Which argument required the slice to be `max_bounded_end` long, starting at 0.
+    pub argument: u16,
+}
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "argument {} requires a slice of at least {} elements, but the slice has {} elements",
+            self.argument, self.max_bounded_end, self.slice_len,
+        )
+    }
 }
 
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::PrevEndIsUnbounded { current_index } => write!(
+                f,
+                "argument {} has an unbounded start, and the previous argument has an unbounded end",
+                current_index,
+            ),
+            Error::NextStartIsUnbounded { current_index } => write!(
+                f,
+                "argument {} has an unbounded end, and the next argument has an unbounded start",
+                current_index,
+            ),
+            Error::NextStartIsLessThanCurrent { current_index } => write!(
+                f,
+                "argument {} has an unbounded end, and the next argument starts before it",
+                current_index,
+            ),
+            Error::InclusiveUptoUsizeMax { current_index } => write!(
+                f,
+                "argument {} ends at usize::MAX inclusive, which is not supported",
+                current_index,
+            ),
+            Error::OverlappingIndexArgs { left, right } => {
+                write!(f, "argument {} overlaps with argument {}", left, right)
+            }
+            Error::TypedArgumentLength {
+                current_index,
+                type_size,
+            } => write!(
+                f,
+                "argument {} is a typed argument, which must be a range of {} elements",
+                current_index, type_size,
+            ),
+            Error::MismatchedLengths { left, right } => write!(
+                f,
+                "argument {} and argument {} must both be indices, or bounded ranges of the same length",
+                left, right,
+            ),
+            Error::NotAPermutation { current_index, len } => write!(
+                f,
+                "argument {} must be an index less than {}, the amount of arguments",
+                current_index, len,
+            ),
+            Error::GapBeforeArgument {
+                current_index,
+                expected_start,
+            } => write!(
+                f,
+                "argument {} must start at {}, leaving no gap before it",
+                current_index, expected_start,
+            ),
+            Error::RuntimeArgument { current_index } => write!(
+                f,
+                "argument {} is a runtime argument, which this macro doesn't take",
+                current_index,
+            ),
+            Error::FieldTooWide {
+                current_index,
+                width,
+            } => write!(
+                f,
+                "argument {} is {} bits long, bit fields can't be longer than {} bits",
+                current_index, width, MAX_FIELD_BITS,
+            ),
+            Error::OutOfBounds(x) => fmt::Display::fmt(&x, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfBounds {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
 #[derive(Debug, Copy, Clone)]
 pub struct ErrorTuple {
     pub kind: ErrorKind,
@@ -47,6 +213,30 @@ pub const fn result_to_tuple(result: Result<(), Error>) -> ErrorTuple {
     }
 }
 
+/// Converts the result of the compile-time checks to an `ErrorTuple`,
+/// which `error_tuple_to_error_type` turns into a type error.
+#[cfg(not(feature = "rust_1_57"))]
+pub const fn check_result(
+    result: Result<(), Error>,
+    _prenorm: &[PrenormIndex],
+    _ind_args: &[IndexArgument],
+) -> ErrorTuple {
+    result_to_tuple(result)
+}
+
+/// Panics with a human-readable message if the compile-time checks errored.
+#[cfg(feature = "rust_1_57")]
+pub const fn check_result(
+    result: Result<(), Error>,
+    prenorm: &[PrenormIndex],
+    ind_args: &[IndexArgument],
+) -> ErrorTuple {
+    match result {
+        Ok(()) => ErrorTuple::OK,
+        Err(m_error) => m_error.panic(prenorm, ind_args),
+    }
+}
+
 impl Error {
     pub const fn to_tuple(&self) -> ErrorTuple {
         match *self {
@@ -65,55 +255,52 @@ impl Error {
             Error::OverlappingIndexArgs { left, right } => {
                 ErrorTuple::new(ErrorKind::OverlappingIndexArgs, left as _, right as _)
             }
+            Error::TypedArgumentLength {
+                current_index,
+                type_size,
+            } => ErrorTuple::new(
+                ErrorKind::TypedArgumentLength,
+                current_index as _,
+                type_size,
+            ),
+            Error::MismatchedLengths { left, right } => {
+                ErrorTuple::new(ErrorKind::MismatchedLengths, left as _, right as _)
+            }
+            Error::NotAPermutation { current_index, len } => {
+                ErrorTuple::new(ErrorKind::NotAPermutation, current_index as _, len as _)
+            }
+            Error::GapBeforeArgument {
+                current_index,
+                expected_start,
+            } => ErrorTuple::new(
+                ErrorKind::GapBeforeArgument,
+                current_index as _,
+                expected_start,
+            ),
+            Error::RuntimeArgument { current_index } => {
+                ErrorTuple::new(ErrorKind::RuntimeArgument, current_index as _, 0)
+            }
+            Error::FieldTooWide {
+                current_index,
+                width,
+            } => ErrorTuple::new(ErrorKind::FieldTooWide, current_index as _, width),
+            Error::OutOfBounds(OutOfBounds {
+                max_bounded_end,
+                slice_len,
+                ..
+            }) => ErrorTuple::new(ErrorKind::OutOfBounds, max_bounded_end, slice_len),
         }
     }
 
-    /*
-    /// Converts this error to a usize, this is potentially lossy.
-    const fn to_usize(&self) -> usize {
-        const MAX_CURR_IND: u16 = 1000;
-
-        let (discr, index) = match *self {
-            Error::PrevEndIsUnbounded { current_index } => (1, current_index),
-            Error::NextStartIsUnbounded { current_index } => (2, current_index),
-            Error::NextStartIsLessThanCurrent { current_index } => (3, current_index),
-            Error::InclusiveUptoUsizeMax { current_index } => (4, current_index),
-            Error::OverlappingIndexArgs { right, .. } => (5, right),
-        };
-
-        ["current index can't be larger than 1000"][(index > MAX_CURR_IND) as usize];
-
-        (discr * MAX_CURR_IND + index) as usize
-    }
-
-    pub const fn panic(&self) -> ! {
-        let usize_value = self.to_usize();
-
-        macro_rules! panic_if_err {
-            ($variant:ident) => {
-                match self {
-                    Error::$variant { .. } => usize_value,
-                    _ => 0,
-                }
-            };
-        }
-
-        ["Expected previous PrenormIndex to have a bounded end"][panic_if_err!(PrevEndIsUnbounded)];
-
-        ["Expected next PrenormIndex to have a bounded start"][panic_if_err!(NextStartIsUnbounded)];
-
-        ["Expected next PrenormIndex to have a larger or equal start index"]
-            [panic_if_err!(NextStartIsLessThanCurrent)];
-
-        ["multindex does not support `n ..= usize::MAX` ranges,use `n .. usize::MAX` instead"]
-            [panic_if_err!(InclusiveUptoUsizeMax)];
-
-        ["At least one of the indices/range arguments overlaps with another one"]
-            [panic_if_err!(OverlappingIndexArgs)];
-
-        loop {}
+    /// Panics with a message that describes this error,
+    /// including the values of the arguments involved.
+    ///
+    /// `prenorm` are the arguments as written in the macro invocation,
+    /// and `ind_args` are their normalized versions.
+    #[cfg(feature = "rust_1_57")]
+    pub const fn panic(&self, prenorm: &[PrenormIndex], ind_args: &[IndexArgument]) -> ! {
+        panic!("{}", self.panic_message(prenorm, ind_args).as_str())
     }
-    */
 }
 
 macro_rules! declare_error_tys {
@@ -154,4 +341,11 @@ declare_error_tys!(
     NextStartIsLessThanCurrent => NextStartIsLessThanCurrent__CurrentArgumentIs<A>,
     InclusiveUptoUsizeMax      => InclusiveUptoUsizeMax__CurrentArgumentis<A>,
     OverlappingIndexArgs       => OverlappingIndexArguments__ArgumentsAre<A, B>,
+    TypedArgumentLength        => TypedArgumentLength__ArgumentAndTypeSizeAre<A, B>,
+    MismatchedLengths          => MismatchedLengths__ArgumentsAre<A, B>,
+    NotAPermutation            => NotAPermutation__ArgumentAndLengthAre<A, B>,
+    GapBeforeArgument          => GapBeforeArgument__ArgumentAndExpectedStartAre<A, B>,
+    RuntimeArgument            => RuntimeArgument__CurrentArgumentIs<A>,
+    FieldTooWide               => FieldTooWide__ArgumentAndWidthAre<A, B>,
+    OutOfBounds                => OutOfBounds__MaxEndAndSliceLenAre<A, B>,
 );
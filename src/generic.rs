@@ -0,0 +1,505 @@
+/*!
+Indexing with arguments derived from const generic parameters.
+
+The indexing macros only take arguments that are computed in constants,
+which can't use the generic parameters of the surrounding function.
+
+The functions in this module take the arguments as types instead
+(eg: `(Index<I>, Array<1, LEN>, RangeFull)`),
+checking that they don't overlap
+(and that unbounded ranges are next to bounded arguments)
+when the function that uses them is instantiated.
+These checks are not reported by `cargo check`, only when the code is compiled to a binary.
+
+This module requires the "rust_1_57" feature.
+
+# Arguments
+
+These are the argument types, and what they're equivalent to in the indexing macros:
+
+- [`Index<I>`](./struct.Index.html): `I`,
+  returns a reference to the element at that index.
+
+- [`Array<START, LEN>`](./struct.Array.html): `START..START + LEN`,
+  returns a reference to a `[T; LEN]` array.
+
+- [`Range<START, END>`](./struct.Range.html): `START..END`, returns a slice.
+
+- [`RangeFrom<START>`](./struct.RangeFrom.html): `START..`, returns a slice.
+
+- [`RangeTo<END>`](./struct.RangeTo.html): `..END`, returns a slice.
+
+- [`RangeFull`](./struct.RangeFull.html): `..`, returns a slice.
+
+Ranges return slices because their lengths can't be computed in types,
+and unbounded ranges get their bounds from the arguments next to them,
+the same way that they do in the indexing macros.
+
+# Example
+
+```rust
+use multindex::generic::{self, Array, Index, RangeFull};
+
+fn split_header<const H: usize>(buf: &mut [u8]) -> (&mut [u8; H], &mut [u8]) {
+    generic::index_mut::<(Array<0, H>, RangeFull), _>(buf)
+}
+
+fn pick<const I: usize, const J: usize>(buf: &[u8]) -> Option<(&u8, &u8)> {
+    generic::get::<(Index<I>, Index<J>), _>(buf)
+}
+
+let mut buf = [3, 5, 8, 13, 21, 34];
+
+let (header, rest) = split_header::<2>(&mut buf);
+header.swap(0, 1);
+rest[0] = 0;
+assert_eq!(buf, [5, 3, 0, 13, 21, 34]);
+
+assert_eq!(pick::<1, 4>(&buf), Some((&3, &21)));
+assert_eq!(pick::<1, 6>(&buf), None);
+
+```
+
+This doesn't compile, because the `Index<2>` argument overlaps with the `Array<0, 4>` one.
+
+```compile_fail
+use multindex::generic::{self, Array, Index};
+
+fn foo<const H: usize>(buf: &mut [u8]) -> (&mut [u8; H], &mut u8) {
+    generic::index_mut::<(Array<0, H>, Index<2>), _>(buf)
+}
+
+let _ = foo::<4>(&mut [0; 10]);
+```
+
+*/
+
+use crate::{
+    are_disjoint::AreAllDisjoint,
+    error::{check_result, ErrorTuple},
+    index_argument::{IndexArgument, PrenormIndex},
+    index_properties::{ComputedConstants, IndexProperties},
+    utils::panic_on_oob_max_index,
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// An index argument, equivalent to `I` in the indexing macros.
+pub struct Index<const I: usize>;
+
+/// A range argument, equivalent to `START..START + LEN` in the indexing macros,
+/// returning a reference to an array.
+pub struct Array<const START: usize, const LEN: usize>;
+
+/// A range argument, equivalent to `START..END` in the indexing macros,
+/// returning a slice.
+pub struct Range<const START: usize, const END: usize>;
+
+/// A range argument, equivalent to `START..` in the indexing macros,
+/// returning a slice.
+pub struct RangeFrom<const START: usize>;
+
+/// A range argument, equivalent to `..END` in the indexing macros,
+/// returning a slice.
+pub struct RangeTo<const END: usize>;
+
+/// A range argument, equivalent to `..` in the indexing macros,
+/// returning a slice.
+pub struct RangeFull;
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// An index/range argument for the functions in this module.
+///
+/// This trait is sealed, it's only implemented by the argument types in this module.
+pub trait IndexArg<'a, T: 'a>: Sealed {
+    /// The type returned when indexing immutably.
+    type Ref;
+    /// The type returned when indexing mutably.
+    type Mut;
+
+    #[doc(hidden)]
+    const PRENORM: PrenormIndex;
+
+    #[doc(hidden)]
+    unsafe fn index_ptr(base: *const T, ind_arg: &IndexArgument, slice_len: usize) -> Self::Ref;
+
+    #[doc(hidden)]
+    unsafe fn index_ptr_mut(base: *mut T, ind_arg: &IndexArgument, slice_len: usize) -> Self::Mut;
+}
+
+impl<const I: usize> Sealed for Index<I> {}
+
+impl<'a, T: 'a, const I: usize> IndexArg<'a, T> for Index<I> {
+    type Ref = &'a T;
+    type Mut = &'a mut T;
+
+    const PRENORM: PrenormIndex = PrenormIndex::Index(I);
+
+    #[inline(always)]
+    unsafe fn index_ptr(base: *const T, ind_arg: &IndexArgument, _: usize) -> Self::Ref {
+        &*base.add(ind_arg.start)
+    }
+
+    #[inline(always)]
+    unsafe fn index_ptr_mut(base: *mut T, ind_arg: &IndexArgument, _: usize) -> Self::Mut {
+        &mut *base.add(ind_arg.start)
+    }
+}
+
+impl<const START: usize, const LEN: usize> Sealed for Array<START, LEN> {}
+
+impl<'a, T: 'a, const START: usize, const LEN: usize> IndexArg<'a, T> for Array<START, LEN> {
+    type Ref = &'a [T; LEN];
+    type Mut = &'a mut [T; LEN];
+
+    const PRENORM: PrenormIndex = PrenormIndex::Range {
+        start: Some(START),
+        end: Some(START + LEN),
+    };
+
+    #[inline(always)]
+    unsafe fn index_ptr(base: *const T, ind_arg: &IndexArgument, _: usize) -> Self::Ref {
+        &*(base.add(ind_arg.start) as *const [T; LEN])
+    }
+
+    #[inline(always)]
+    unsafe fn index_ptr_mut(base: *mut T, ind_arg: &IndexArgument, _: usize) -> Self::Mut {
+        &mut *(base.add(ind_arg.start) as *mut [T; LEN])
+    }
+}
+
+macro_rules! impl_slice_index_arg {
+    ($(
+        impl[$($impl_params:tt)*] $ty:ty => $prenorm:expr;
+    )*) => ($(
+        impl<$($impl_params)*> Sealed for $ty {}
+
+        impl<'a, T: 'a, $($impl_params)*> IndexArg<'a, T> for $ty {
+            type Ref = &'a [T];
+            type Mut = &'a mut [T];
+
+            const PRENORM: PrenormIndex = $prenorm;
+
+            #[inline(always)]
+            unsafe fn index_ptr(
+                base: *const T,
+                ind_arg: &IndexArgument,
+                slice_len: usize,
+            ) -> Self::Ref {
+                let end = option_unwrap_or_else!(ind_arg.end(), slice_len);
+                core::slice::from_raw_parts(base.add(ind_arg.start), end - ind_arg.start)
+            }
+
+            #[inline(always)]
+            unsafe fn index_ptr_mut(
+                base: *mut T,
+                ind_arg: &IndexArgument,
+                slice_len: usize,
+            ) -> Self::Mut {
+                let end = option_unwrap_or_else!(ind_arg.end(), slice_len);
+                core::slice::from_raw_parts_mut(base.add(ind_arg.start), end - ind_arg.start)
+            }
+        }
+    )*)
+}
+
+impl_slice_index_arg! {
+    impl[const START: usize, const END: usize] Range<START, END> =>
+        PrenormIndex::Range { start: Some(START), end: Some(END) };
+
+    impl[const START: usize] RangeFrom<START> =>
+        PrenormIndex::Range { start: Some(START), end: None };
+
+    impl[const END: usize] RangeTo<END> =>
+        PrenormIndex::Range { start: None, end: Some(END) };
+
+    impl[] RangeFull =>
+        PrenormIndex::Range { start: None, end: None };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A tuple of [`IndexArg`]s, for the functions in this module.
+///
+/// This trait is sealed, it's only implemented by tuples of up to 12 arguments.
+///
+/// [`IndexArg`]: ./trait.IndexArg.html
+pub trait IndexArgs<'a, T: 'a>: Sealed {
+    /// The tuple returned when indexing immutably.
+    type Ref;
+    /// The tuple returned when indexing mutably.
+    type Mut;
+
+    #[doc(hidden)]
+    const SHARED: &'static ComputedConstants<[IndexArgument]>;
+
+    #[doc(hidden)]
+    const MUT: &'static ComputedConstants<[IndexArgument]>;
+
+    #[doc(hidden)]
+    unsafe fn index_ptr(base: *const T, ind_args: &[IndexArgument], slice_len: usize) -> Self::Ref;
+
+    #[doc(hidden)]
+    unsafe fn index_ptr_mut(
+        base: *mut T,
+        ind_args: &[IndexArgument],
+        slice_len: usize,
+    ) -> Self::Mut;
+}
+
+/// The const fn equivalent of what the indexing macros compute in constants,
+/// panicking if the arguments are invalid.
+const fn compute_constants<const N: usize>(
+    prenorm: &'static [PrenormIndex],
+    expected_are_disjoint: AreAllDisjoint,
+) -> ComputedConstants<[IndexArgument; N]> {
+    let mut ind_args = [IndexArgument::EMPTY; N];
+    let mut err = Ok(());
+
+    let iaas = new_IndexArgumentsAndStats! {
+        @initialize
+        prenorm = prenorm,
+        ind_args = ind_args,
+        error_handling(|e| {
+            err = Err(e);
+            break;
+        }),
+    };
+
+    let mut comp_consts = ComputedConstants {
+        err,
+        err_tuple: ErrorTuple::OK,
+        prenorm,
+        stats: iaas.stats,
+        ind_args: iaas.ind_args,
+    };
+
+    if let Ok(()) = comp_consts.err {
+        let props = IndexProperties::new(
+            &comp_consts.ind_args,
+            &comp_consts.stats,
+            expected_are_disjoint,
+        );
+        comp_consts.err = props.are_disjoint.check_is_expected(&expected_are_disjoint);
+    }
+
+    comp_consts.err_tuple = check_result(comp_consts.err, prenorm, &comp_consts.ind_args);
+    comp_consts
+}
+
+macro_rules! impl_index_args {
+    ([$($prev:tt)*] [($ty:ident $index:tt $len:tt) $($rem:tt)*]) => {
+        impl_index_args!{@impl $len [$($prev)* ($ty $index)]}
+        impl_index_args!{[$($prev)* ($ty $index)] [$($rem)*]}
+    };
+    ([$($prev:tt)*] []) => {};
+    (@impl $len:tt [$(($ty:ident $index:tt))*]) => {
+        impl<$($ty,)*> Sealed for ($($ty,)*)
+        where
+            $($ty: Sealed,)*
+        {}
+
+        impl<'a, T: 'a, $($ty,)*> IndexArgs<'a, T> for ($($ty,)*)
+        where
+            $($ty: IndexArg<'a, T>,)*
+        {
+            type Ref = ($($ty::Ref,)*);
+            type Mut = ($($ty::Mut,)*);
+
+            const SHARED: &'static ComputedConstants<[IndexArgument]> =
+                &compute_constants::<$len>(&[$($ty::PRENORM,)*], AreAllDisjoint::NO);
+
+            const MUT: &'static ComputedConstants<[IndexArgument]> =
+                &compute_constants::<$len>(&[$($ty::PRENORM,)*], AreAllDisjoint::YES);
+
+            #[inline(always)]
+            unsafe fn index_ptr(
+                base: *const T,
+                ind_args: &[IndexArgument],
+                slice_len: usize,
+            ) -> Self::Ref {
+                ($($ty::index_ptr(base, &ind_args[$index], slice_len),)*)
+            }
+
+            #[inline(always)]
+            unsafe fn index_ptr_mut(
+                base: *mut T,
+                ind_args: &[IndexArgument],
+                slice_len: usize,
+            ) -> Self::Mut {
+                ($($ty::index_ptr_mut(base, &ind_args[$index], slice_len),)*)
+            }
+        }
+    };
+}
+
+impl_index_args! {
+    []
+    [
+        (A0 0 1) (A1 1 2) (A2 2 3) (A3 3 4) (A4 4 5) (A5 5 6)
+        (A6 6 7) (A7 7 8) (A8 8 9) (A9 9 10) (A10 10 11) (A11 11 12)
+    ]
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// For immutable indexing of slices with the `A` arguments.
+///
+/// This is the equivalent of [`multindex`](../macro.multindex.html)
+/// for the arguments in this module.
+///
+/// # Panics
+///
+/// This panics if the arguments are out of bounds for the passed slice.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::generic::{self, Index, RangeFull};
+///
+/// fn last_and_rest<const N: usize>(slice: &[u32]) -> (&[u32], &u32) {
+///     generic::index::<(RangeFull, Index<N>), _>(slice)
+/// }
+///
+/// assert_eq!(last_and_rest::<2>(&[3, 5, 8, 13]), (&[3, 5][..], &8));
+/// assert_eq!(last_and_rest::<3>(&[3, 5, 8, 13]), (&[3, 5, 8][..], &13));
+///
+/// ```
+#[inline]
+pub fn index<'a, A, T>(slice: &'a [T]) -> A::Ref
+where
+    A: IndexArgs<'a, T>,
+{
+    let comp_consts = A::SHARED;
+    if comp_consts.stats.max_bounded_end > slice.len() {
+        panic_on_oob_max_index(comp_consts.stats.max_bounded_end, slice.len());
+    }
+    unsafe { A::index_ptr(slice.as_ptr(), &comp_consts.ind_args, slice.len()) }
+}
+
+/// For mutable indexing of slices with the `A` arguments.
+///
+/// This is the equivalent of [`multindex_mut`](../macro.multindex_mut.html)
+/// for the arguments in this module.
+///
+/// # Panics
+///
+/// This panics if the arguments are out of bounds for the passed slice.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::generic::{self, Array, RangeFrom};
+///
+/// fn rotate_header<const H: usize>(slice: &mut [u32]) -> &mut [u32] {
+///     let (header, rest) = generic::index_mut::<(Array<0, H>, RangeFrom<H>), _>(slice);
+///     header.rotate_left(1);
+///     rest
+/// }
+///
+/// let mut arr = [3, 5, 8, 13, 21];
+/// assert_eq!(rotate_header::<3>(&mut arr), [13, 21]);
+/// assert_eq!(arr, [5, 8, 3, 13, 21]);
+///
+/// ```
+#[inline]
+pub fn index_mut<'a, A, T>(slice: &'a mut [T]) -> A::Mut
+where
+    A: IndexArgs<'a, T>,
+{
+    let comp_consts = A::MUT;
+    if comp_consts.stats.max_bounded_end > slice.len() {
+        panic_on_oob_max_index(comp_consts.stats.max_bounded_end, slice.len());
+    }
+    unsafe { A::index_ptr_mut(slice.as_mut_ptr(), &comp_consts.ind_args, slice.len()) }
+}
+
+/// For fallible immutable indexing of slices with the `A` arguments.
+///
+/// This is the equivalent of [`multiget`](../macro.multiget.html)
+/// for the arguments in this module,
+/// returning `None` if the arguments are out of bounds for the passed slice.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::generic::{self, Array, RangeFull};
+///
+/// fn split_first_n<const N: usize>(slice: &[u32]) -> Option<(&[u32; N], &[u32])> {
+///     generic::get::<(Array<0, N>, RangeFull), _>(slice)
+/// }
+///
+/// assert_eq!(split_first_n::<2>(&[3, 5, 8]), Some((&[3, 5], &[8][..])));
+/// assert_eq!(split_first_n::<3>(&[3, 5, 8]), Some((&[3, 5, 8], &[][..])));
+/// assert_eq!(split_first_n::<4>(&[3, 5, 8]), None);
+///
+/// ```
+#[inline]
+pub fn get<'a, A, T>(slice: &'a [T]) -> Option<A::Ref>
+where
+    A: IndexArgs<'a, T>,
+{
+    let comp_consts = A::SHARED;
+    if comp_consts.stats.max_bounded_end > slice.len() {
+        None
+    } else {
+        unsafe {
+            Some(A::index_ptr(
+                slice.as_ptr(),
+                &comp_consts.ind_args,
+                slice.len(),
+            ))
+        }
+    }
+}
+
+/// For fallible mutable indexing of slices with the `A` arguments.
+///
+/// This is the equivalent of [`multiget_mut`](../macro.multiget_mut.html)
+/// for the arguments in this module,
+/// returning `None` if the arguments are out of bounds for the passed slice.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::generic::{self, Index};
+///
+/// fn swap<const I: usize, const J: usize>(slice: &mut [u32]) -> bool {
+///     generic::get_mut::<(Index<I>, Index<J>), _>(slice)
+///         .map(|(a, b)| core::mem::swap(a, b))
+///         .is_some()
+/// }
+///
+/// let mut arr = [3, 5, 8, 13];
+///
+/// assert!(swap::<0, 3>(&mut arr));
+/// assert_eq!(arr, [13, 5, 8, 3]);
+///
+/// assert!(!swap::<0, 4>(&mut arr));
+/// assert_eq!(arr, [13, 5, 8, 3]);
+///
+/// ```
+#[inline]
+pub fn get_mut<'a, A, T>(slice: &'a mut [T]) -> Option<A::Mut>
+where
+    A: IndexArgs<'a, T>,
+{
+    let comp_consts = A::MUT;
+    if comp_consts.stats.max_bounded_end > slice.len() {
+        None
+    } else {
+        unsafe {
+            Some(A::index_ptr_mut(
+                slice.as_mut_ptr(),
+                &comp_consts.ind_args,
+                slice.len(),
+            ))
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use crate::{
-    error::Error,
+    are_disjoint::AreAllDisjoint,
+    bits::MAX_FIELD_BITS,
+    error::{Error, OutOfBounds},
     index_properties::IndexArgumentStats,
-    std_const_fns::{slice_m, usize_m},
+    std_const_fns::{option_m, slice_m, usize_m},
 };
 
 #[cfg(feature = "testing")]
@@ -91,6 +93,38 @@ impl IntoPrenormIndex<RangeToInclusive<usize>> {
     }
 }
 
+/// An index relative to the end of the slice, written as `end - N` in the indexing macros.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexFromEnd(pub usize);
+
+/// A range relative to the end of the slice,
+/// written as `end - N ..`, `end - N .. end`, or `end - N .. end - M` in the indexing macros.
+///
+/// `start` and `end` are how many elements before the end of the slice the range starts/ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeFromEnd {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl IntoPrenormIndex<IndexFromEnd> {
+    #[inline]
+    pub const fn call(self) -> PrenormIndex {
+        let IndexFromEnd(start) = self.0;
+        PrenormIndex::IndexFromEnd(start)
+    }
+}
+
+impl IntoPrenormIndex<RangeFromEnd> {
+    #[inline]
+    pub const fn call(self) -> PrenormIndex {
+        PrenormIndex::RangeFromEnd {
+            start: self.0.start,
+            end: self.0.end,
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -104,6 +138,18 @@ pub enum PrenormIndex {
     InclusiveToMax {
         start: Option<usize>,
     },
+    /// A placeholder for an argument that is only known at runtime,
+    /// its start and end are treated as unbounded by the arguments next to it.
+    Runtime,
+    /// An index relative to the end of the slice,
+    /// normalized at runtime once the length of the slice is known.
+    IndexFromEnd(usize),
+    /// A range relative to the end of the slice,
+    /// normalized at runtime once the length of the slice is known.
+    RangeFromEnd {
+        start: usize,
+        end: usize,
+    },
 }
 
 impl PrenormIndex {
@@ -112,14 +158,71 @@ impl PrenormIndex {
             Self::Index(i) => Some(i),
             Self::Range { start, .. } => start,
             Self::InclusiveToMax { start } => start,
+            Self::Runtime | Self::IndexFromEnd(_) | Self::RangeFromEnd { .. } => None,
         }
     }
     const fn end(&self) -> Option<usize> {
         match *self {
-            Self::Index(i) => Some(i + 1),
+            Self::Index(i) => {
+                let (end, overflowed) = i.overflowing_add(1);
+                if overflowed {
+                    None
+                } else {
+                    Some(end)
+                }
+            }
             Self::Range { end, .. } => end,
             Self::InclusiveToMax { .. } => None,
+            Self::Runtime | Self::IndexFromEnd(_) | Self::RangeFromEnd { .. } => None,
+        }
+    }
+
+    /// Whether this is an argument relative to the end of the slice.
+    pub const fn is_from_end(&self) -> bool {
+        matches!(self, Self::IndexFromEnd(_) | Self::RangeFromEnd { .. })
+    }
+
+    /// The length of a range relative to the end of the slice,
+    /// which is known at compile-time.
+    pub const fn from_end_len(&self) -> usize {
+        match *self {
+            Self::RangeFromEnd { start, end } => usize_m::saturating_sub(start, end),
+            _ => 0,
+        }
+    }
+
+    /// Converts an argument relative to the end of a slice of length `slice_len`
+    /// into one relative to its start,
+    /// erroring if the argument starts before the start of the slice.
+    ///
+    /// The other arguments are returned unchanged.
+    pub const fn resolve_from_end(
+        self,
+        slice_len: usize,
+        current_index: u16,
+    ) -> Result<Self, Error> {
+        let from_end = match self {
+            Self::IndexFromEnd(start) => start,
+            Self::RangeFromEnd { start, .. } => start,
+            _ => return Ok(self),
+        };
+        if from_end > slice_len {
+            return Err(Error::OutOfBounds(OutOfBounds {
+                slice_len,
+                max_bounded_end: from_end,
+                argument: current_index,
+            }));
         }
+        Ok(match self {
+            Self::RangeFromEnd { start, end } => Self::Range {
+                start: Some(slice_len - start),
+                end: Some(usize_m::max(
+                    slice_len - start,
+                    usize_m::saturating_sub(slice_len, end),
+                )),
+            },
+            _ => Self::Index(slice_len - from_end),
+        })
     }
 }
 
@@ -164,7 +267,13 @@ impl IndexArgument {
 
         let this = match prenorm[i] {
             PrenormIndex::Index(start) => {
-                candidate_max_end = start + 1;
+                // Indices can be runtime values (in the `*_dyn*` macros),
+                // so this can't rely on overflow being a compile-time error.
+                let (end, overflowed) = start.overflowing_add(1);
+                if overflowed {
+                    return Err(Error::InclusiveUptoUsizeMax { current_index });
+                }
+                candidate_max_end = end;
 
                 Self {
                     start,
@@ -195,6 +304,16 @@ impl IndexArgument {
 
                     index_kind = IndexKind::Range;
                     saturated_len = len;
+                } else if let Some(PrenormIndex::IndexFromEnd(_))
+                | Some(PrenormIndex::RangeFromEnd { .. }) = slice_m::get(prenorm, i + 1)
+                {
+                    // The range ends where the next argument starts,
+                    // which is only known at runtime,
+                    // so only the start of it needs to be bounds checked.
+                    candidate_max_end = start;
+
+                    index_kind = IndexKind::RangeFrom;
+                    saturated_len = usize::MAX - start;
                 } else if let Some(next) = slice_m::get(&prenorm, i + 1) {
                     let next_start = option_unwrap_or_else!(
                         next.start(),
@@ -230,19 +349,358 @@ impl IndexArgument {
             PrenormIndex::InclusiveToMax { .. } => {
                 return Err(Error::InclusiveUptoUsizeMax { current_index })
             }
+            // Runtime arguments and arguments relative to the end of the slice
+            // are normalized and checked at runtime,
+            // the empty placeholder doesn't intersect any other argument.
+            PrenormIndex::Runtime
+            | PrenormIndex::IndexFromEnd(_)
+            | PrenormIndex::RangeFromEnd { .. } => return Ok((Self::EMPTY, stats)),
         };
 
         let prev_max_bounded_end = mem_replace!(
             stats.max_bounded_end,
             usize_m::max(stats.max_bounded_end, candidate_max_end)
         );
+        if candidate_max_end > prev_max_bounded_end {
+            stats.max_bounded_end_arg = current_index;
+        }
         stats.are_sorted = stats.are_sorted && prev_max_bounded_end <= this.start;
 
+        // A range with an unbounded end that isn't the last argument
+        // extends up to the arguments relative to the end of the slice,
+        // so the arguments after it must be checked against it.
+        if let IndexKind::RangeFrom = this.index_kind {
+            stats.are_sorted = stats.are_sorted && i + 1 == prenorm.len();
+        }
+
         Ok((this, stats))
     }
 }
 
 impl IndexArgument {
+    /// Checks that typed arguments (eg: `u32be @ 0..4`) are ranges as long as their type.
+    ///
+    /// `type_sizes` has the size of the type of each typed argument,
+    /// and `None` for the other arguments.
+    pub const fn check_type_sizes(
+        ind_args: &[IndexArgument],
+        type_sizes: &[Option<usize>],
+    ) -> Result<(), Error> {
+        for_range! {i in 0..ind_args.len() =>
+            if let Some(type_size) = type_sizes[i] {
+                let ind_arg = &ind_args[i];
+                let is_range = matches!(ind_arg.index_kind, IndexKind::Range);
+                if !is_range || ind_arg.saturated_len != type_size {
+                    return Err(Error::TypedArgumentLength {
+                        current_index: i as u16,
+                        type_size,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that each pair of arguments are the same kind of argument,
+    /// with the same length, and that neither has an unbounded end.
+    ///
+    /// `pairs` has the positions of the arguments in each pair.
+    pub const fn check_same_lengths(
+        ind_args: &[IndexArgument],
+        pairs: &[[u16; 2]],
+    ) -> Result<(), Error> {
+        for_range! {i in 0..pairs.len() =>
+            let [left, right] = pairs[i];
+            let l_arg = &ind_args[left as usize];
+            let r_arg = &ind_args[right as usize];
+            let is_unbounded = matches!(l_arg.index_kind, IndexKind::RangeFrom)
+                || matches!(r_arg.index_kind, IndexKind::RangeFrom);
+            let same_kind = l_arg.index_kind as u8 == r_arg.index_kind as u8;
+            if is_unbounded || !same_kind || l_arg.saturated_len != r_arg.saturated_len {
+                return Err(Error::MismatchedLengths { left, right });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that every argument is an index less than the amount of arguments,
+    /// which (combined with the arguments not overlapping)
+    /// means that they're a permutation of `0..ind_args.len()`.
+    pub const fn check_permutation(ind_args: &[IndexArgument]) -> Result<(), Error> {
+        for_range! {i in 0..ind_args.len() =>
+            let ind_arg = &ind_args[i];
+            let is_index = matches!(ind_arg.index_kind, IndexKind::Index);
+            if !is_index || ind_arg.start >= ind_args.len() {
+                return Err(Error::NotAPermutation {
+                    current_index: i as u16,
+                    len: ind_args.len() as u16,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the arguments with a bounded end are at most
+    /// `MAX_FIELD_BITS` long, for the bit fields of the `bitindex` macro.
+    ///
+    /// The width of a trailing unbounded range depends on the indexed integer/slice,
+    /// so it's checked at runtime instead.
+    pub const fn check_field_widths(ind_args: &[IndexArgument]) -> Result<(), Error> {
+        for_range! {i in 0..ind_args.len() =>
+            let ind_arg = &ind_args[i];
+            if let Some(end) = ind_arg.end() {
+                let width = end - ind_arg.start;
+                if width > MAX_FIELD_BITS {
+                    return Err(Error::FieldTooWide {
+                        current_index: i as u16,
+                        width,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the arguments cover every element from 0 up to the end of the last one,
+    /// leaving no gaps between them.
+    ///
+    /// This requires the arguments to be in ascending order,
+    /// each one starting where the previous one ends.
+    /// Runtime arguments aren't allowed, since they can't be checked at compile-time.
+    pub const fn check_exhaustive(
+        prenorm: &[PrenormIndex],
+        ind_args: &[IndexArgument],
+    ) -> Result<(), Error> {
+        if let Err(e) = Self::check_no_runtime(prenorm) {
+            return Err(e);
+        }
+
+        let mut expected_start = 0;
+        for_range! {i in 0..ind_args.len() =>
+            if ind_args[i].start != expected_start {
+                return Err(Error::GapBeforeArgument {
+                    current_index: i as u16,
+                    expected_start,
+                });
+            }
+            expected_start = ind_args[i].saturated_end();
+        }
+        Ok(())
+    }
+
+    /// Checks that none of the arguments are runtime arguments
+    /// (including the ones relative to the end of the slice),
+    /// for the macros that need to know every argument at compile-time.
+    pub const fn check_no_runtime(prenorm: &[PrenormIndex]) -> Result<(), Error> {
+        for_range! {i in 0..prenorm.len() =>
+            if let PrenormIndex::Runtime = prenorm[i] {
+                return Err(Error::RuntimeArgument { current_index: i as u16 });
+            }
+            if prenorm[i].is_from_end() {
+                return Err(Error::RuntimeArgument { current_index: i as u16 });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that none of the arguments are `dyn` arguments,
+    /// for the macros that can only report that the slice is too short
+    /// (eg: `try_multindex_mut`).
+    ///
+    /// Unlike `check_no_runtime`, this allows arguments relative to the end of the slice.
+    pub const fn check_no_dyn(prenorm: &[PrenormIndex]) -> Result<(), Error> {
+        for_range! {i in 0..prenorm.len() =>
+            if let PrenormIndex::Runtime = prenorm[i] {
+                return Err(Error::RuntimeArgument { current_index: i as u16 });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the arguments relative to the end of the slice don't overlap
+    /// each other, nor a range with an unbounded end
+    /// (other than one right before them, which ends where they start).
+    ///
+    /// Those overlaps happen regardless of the length of the slice,
+    /// any other overlap with an argument relative to the end of the slice
+    /// is because the slice is too short, and is reported at runtime as `OutOfBounds`.
+    pub const fn check_from_end_disjoint<T>(
+        prenorm: &[PrenormIndex],
+        ind_args: &[IndexArgument],
+        expected_are_disjoint: &AreAllDisjoint<T>,
+    ) -> Result<(), Error> {
+        for_range! {right in 0..prenorm.len() =>
+            if prenorm[right].is_from_end() {
+                let (r_start, r_end) = Self::distances_from_end(prenorm, ind_args, right);
+                for_range! {left in 0..prenorm.len() =>
+                    let (l_start, l_end) = Self::distances_from_end(prenorm, ind_args, left);
+                    if left != right
+                        && expected_are_disjoint.must_be_disjoint(left, right)
+                        && usize_m::max(l_start, r_start) < usize_m::min(l_end, r_end)
+                    {
+                        return Err(Error::OverlappingIndexArgs {
+                            left: usize_m::min(left, right) as u16,
+                            right: usize_m::max(left, right) as u16,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The distances from the end of an arbitrarily long slice of the elements that
+    /// the argument at `i` covers, as a `start..end` range,
+    /// where the last element of the slice is at distance 1.
+    ///
+    /// Arguments that aren't relative to the end of the slice,
+    /// and don't have an unbounded end, return an empty range.
+    const fn distances_from_end(
+        prenorm: &[PrenormIndex],
+        ind_args: &[IndexArgument],
+        i: usize,
+    ) -> (usize, usize) {
+        match prenorm[i] {
+            PrenormIndex::IndexFromEnd(start) => (start, usize_m::saturating_add(start, 1)),
+            PrenormIndex::RangeFromEnd { start, end } => (
+                usize_m::saturating_add(end, 1),
+                usize_m::saturating_add(start, 1),
+            ),
+            _ if matches!(ind_args[i].index_kind, IndexKind::RangeFrom) => {
+                // Ranges with an unbounded end before an argument relative to the end
+                // of the slice end where that argument starts.
+                match slice_m::get(prenorm, i + 1) {
+                    Some(PrenormIndex::IndexFromEnd(start))
+                    | Some(PrenormIndex::RangeFromEnd { start, .. }) => {
+                        (usize_m::saturating_add(*start, 1), usize::MAX)
+                    }
+                    _ => (1, usize::MAX),
+                }
+            }
+            _ => (0, 0),
+        }
+    }
+
+    /// Gets the first region at or after `from` that isn't covered by any argument,
+    /// returning `None` if the rest of the slice is covered.
+    ///
+    /// The region ends at the start of the next non-empty argument,
+    /// returning an `IndexKind::RangeFrom` region if there's no argument after it.
+    pub const fn next_uncovered(ind_args: &[IndexArgument], from: usize) -> Option<Self> {
+        let mut start = from;
+
+        // Skipping past the arguments that cover `start`.
+        let mut i = 0;
+        while i < ind_args.len() {
+            let ind_arg = &ind_args[i];
+            if ind_arg.start <= start && start < ind_arg.saturated_end() {
+                if let IndexKind::RangeFrom = ind_arg.index_kind {
+                    return None;
+                }
+                start = ind_arg.saturated_end();
+                // The arguments aren't sorted, so the previous ones
+                // could cover the new `start`.
+                i = 0;
+            } else {
+                i += 1;
+            }
+        }
+
+        let mut end = None;
+        for_range! {i in 0..ind_args.len() =>
+            let ind_arg = &ind_args[i];
+            let ends_before = match end {
+                Some(end) => ind_arg.start < end,
+                None => true,
+            };
+            if ind_arg.saturated_len != 0 && start < ind_arg.start && ends_before {
+                end = Some(ind_arg.start);
+            }
+        }
+
+        Some(match end {
+            Some(end) => Self {
+                start,
+                index_kind: IndexKind::Range,
+                saturated_len: end - start,
+            },
+            None => Self {
+                start,
+                index_kind: IndexKind::RangeFrom,
+                saturated_len: usize::MAX - start,
+            },
+        })
+    }
+
+    /// The amount of regions that `next_uncovered` returns,
+    /// starting from 0 and continuing from the end of each region.
+    pub const fn count_uncovered(ind_args: &[IndexArgument]) -> usize {
+        let mut count = 0;
+        let mut from = 0;
+        while let Some(region) = Self::next_uncovered(ind_args, from) {
+            count += 1;
+            if let IndexKind::RangeFrom = region.index_kind {
+                break;
+            }
+            from = region.saturated_end();
+        }
+        count
+    }
+
+    /// Recomputes `stats.max_bounded_end` for macros that clamp ranges
+    /// to the length of the slice (eg: `multiget_clamped`),
+    /// where only indices and typed arguments need to be in bounds.
+    ///
+    /// `type_sizes` is the same as in `check_type_sizes`.
+    pub const fn clamped_stats(
+        ind_args: &[IndexArgument],
+        type_sizes: &[Option<usize>],
+        mut stats: IndexArgumentStats,
+    ) -> IndexArgumentStats {
+        stats.max_bounded_end = 0;
+        stats.max_bounded_end_arg = 0;
+        for_range! {i in 0..ind_args.len() =>
+            let ind_arg = &ind_args[i];
+            let is_fixed = option_m::is_some(&type_sizes[i])
+                || matches!(ind_arg.index_kind, IndexKind::Index);
+            if is_fixed && ind_arg.saturated_end() > stats.max_bounded_end {
+                stats.max_bounded_end = ind_arg.saturated_end();
+                stats.max_bounded_end_arg = i as u16;
+            }
+        }
+        stats
+    }
+
+    /// Where the argument at `i` ends in a slice of length `slice_len`,
+    /// if it's a range with an unbounded end.
+    ///
+    /// This is the start of the next argument if that one is relative to the end of the slice,
+    /// otherwise it's `slice_len`.
+    #[inline]
+    pub const fn unbounded_end(prenorm: &[PrenormIndex], i: usize, slice_len: usize) -> usize {
+        match slice_m::get(prenorm, i + 1) {
+            Some(PrenormIndex::IndexFromEnd(start))
+            | Some(PrenormIndex::RangeFromEnd { start, .. }) => {
+                usize_m::saturating_sub(slice_len, *start)
+            }
+            _ => slice_len,
+        }
+    }
+
+    /// Converts an argument with an unbounded end into a range that ends at `end`,
+    /// returning `None` if `end` is before the start of the argument.
+    pub(crate) const fn ending_at(self, end: usize) -> Option<Self> {
+        if end < self.start {
+            None
+        } else {
+            Some(Self {
+                start: self.start,
+                index_kind: IndexKind::Range,
+                saturated_len: end - self.start,
+            })
+        }
+    }
+
     /// Whether an IndexArgument intersects another one.
     #[inline]
     pub(crate) const fn intersects(&self, other: &IndexArgument) -> bool {
@@ -279,6 +737,19 @@ impl IndexArgument {
         }
     }
 
+    /// The minimum length of a slice that this IndexArgument is in bounds for.
+    ///
+    /// This is the start of arguments with an unbounded end,
+    /// and the end of every other argument.
+    #[inline]
+    pub const fn bounded_end(&self) -> usize {
+        if let IndexKind::RangeFrom = self.index_kind {
+            self.start
+        } else {
+            self.saturated_end()
+        }
+    }
+
     /// Gets the exclusive end index of this IndexArgument.
     ///
     /// If `self` has an unbounded end this function returns `usize::MAX`
@@ -301,6 +772,8 @@ pub enum IndexKind {
 pub enum IK_Index {}
 pub enum IK_Range {}
 pub enum IK_RangeFrom {}
+/// A range that's clamped to the length of the slice, returning a slice.
+pub enum IK_RangeClamped {}
 
 make_type_picker! {
     for[] struct IndexKindPicker[];
@@ -310,3 +783,13 @@ make_type_picker! {
         IndexKind::RangeFrom => IK_RangeFrom,
     ];
 }
+
+// The type-level `IndexKind`s of the arguments of the clamped macros (eg: `multiget_clamped`).
+make_type_picker! {
+    for[] struct ClampedIndexKindPicker[];
+    values_to_types = [
+        IndexKind::Range => IK_RangeClamped,
+        IndexKind::Index => IK_Index,
+        IndexKind::RangeFrom => IK_RangeClamped,
+    ];
+}
@@ -1,7 +1,7 @@
 use crate::{
     are_disjoint::AreAllDisjoint,
     error::{Error, ErrorTuple},
-    index_argument::IndexArgument,
+    index_argument::{IndexArgument, PrenormIndex},
 };
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -10,6 +10,9 @@ use crate::{
 pub struct IndexArgumentStats {
     pub max_bounded_end: usize,
 
+    /// Which argument has the `max_bounded_end` end, starting at 0.
+    pub max_bounded_end_arg: u16,
+
     /// Whether all the IndexArgument in a slice are less than the next one,
     /// IndexArguments are less than each other only if the end of one
     /// is less than the start of the next.
@@ -19,6 +22,7 @@ pub struct IndexArgumentStats {
 impl IndexArgumentStats {
     pub const NEW: Self = Self {
         max_bounded_end: 0,
+        max_bounded_end_arg: 0,
         are_sorted: true,
     };
 }
@@ -36,6 +40,8 @@ pub struct IndexArgumentsAndStats<IA: ?Sized> {
 pub struct ComputedConstants<IA: ?Sized> {
     pub err: Result<(), Error>,
     pub err_tuple: ErrorTuple,
+    /// The arguments as written in the macro invocation.
+    pub prenorm: &'static [PrenormIndex],
     pub stats: IndexArgumentStats,
     /// An array of IndexArgument.
     pub ind_args: IA,
@@ -56,14 +62,15 @@ impl IndexProperties {
     ) -> Self {
         Self {
             are_disjoint: block! {'outer:
-                if let (AreAllDisjoint::Yes, false) = (expected, stats.are_sorted) {
+                let checks_disjoint = !matches!(expected, AreAllDisjoint::No(_));
+                if checks_disjoint && !stats.are_sorted {
                     for_range! { i in 0..ind_args.len() =>
                         for_range!{ j in 0..ind_args.len() =>
                             // Because the `intersects` method is symetric,we don't need to check
                             // both ind_args[i].intersects(&ind_args[j])
                             // and  ind_args[j].intersects(&ind_args[i])
                             if j >= i { break }
-                            if ind_args[i].intersects(&ind_args[j]) {
+                            if expected.must_be_disjoint(i, j) && ind_args[i].intersects(&ind_args[j]) {
                                 break 'outer AreAllDisjoint::No(Error::OverlappingIndexArgs{
                                     left: j as u16,
                                     right: i as u16,
@@ -6,8 +6,15 @@ Documents what the indexing macros share in common.
 The  macros from this crate only accept compile-time indices/ranges not
 derived from generic parameters.
 
-The non-generic limitation might be lifted in newer versions,
-when const generics and compile-time function evaluation support improves.
+For indices/ranges derived from const generic parameters,
+there are the functions in the [`generic`] module (which requires the "rust_1_57" feature),
+that take the arguments as types.
+
+For indices/ranges that are only known at runtime,
+there are the [`multindex_dyn`], [`multindex_dyn_mut`], [`multiget_dyn`],
+and [`multiget_dyn_mut`] macros,
+which do all of the checks at runtime,
+returning slices for range arguments.
 
 # Parameters
 
@@ -30,6 +37,43 @@ Returns a slice if the range is unbounded at the end of the argument list.
 Otherwise returns an reference to an array.
 <br>Eg: `..2`, `..=2`, `2..`, `..`.
 
+- Runtime arguments:
+`dyn` followed by an integer or range whose value is only known at runtime.
+Integers return a reference to an element, ranges return a slice.
+The other (constant) arguments are still checked against each other at compile-time,
+while `dyn` arguments are checked against every other argument at runtime,
+panicking (or returning `None`) if they're out of bounds, or overlap in the `*_mut` macros.
+A `dyn` argument is normalized on its own,
+so `dyn n..` is a range up to the end of the slice,
+and constant ranges next to it must be bounded on that side.
+<br>Eg: `dyn i`, `dyn off..off + len`, `dyn start..`.
+
+- Arguments relative to the end of the slice:
+`end - N` for an index, and `end - N ..`, `end - N .. end`, or `end - N .. end - M` for ranges,
+where `N` and `M` are integer literals, constants, or parenthesized constant expressions.
+Integers return a reference to an element, ranges return a reference to an array.
+A `n..`/`..` range right before one of these arguments ends where that argument starts,
+returning a slice.
+Their overlaps with each other, and with ranges with an unbounded end,
+happen for any length of the slice, and are checked at compile-time.
+Their overlaps with the other arguments are checked at runtime, like `dyn` arguments,
+and are reported as the slice being too short.
+<br>Eg: `end - 1`, `end - 4..`, `end - 8..end - 4`.
+
+- Typed arguments:
+One of the types in the [`endian`] module, followed by `@` and a range as long as that type,
+for indexing byte slices.
+The shared macros return the decoded integer/float,
+the mutable macros return a mutable reference to the type in the [`endian`] module,
+which has `get` and `set` methods.
+<br>Eg: `u32be @ 0..4`, `i16le @ 4..6`, `f64le @ ..8`.
+
+- Cast arguments:
+`cast<T> @` followed by a range as long as `T`, for indexing byte slices,
+where `T` implements the [`Pod`] trait.
+Returns a reference to a `T`, panicking if the range isn't aligned for `T`.
+<br>Eg: `cast<Header> @ 0..16`, `cast<[u32; 4]> @ 16..32`.
+
 For an example of using every type of argument [look here](#every-arg-type-example)
 
 # Errors
@@ -44,6 +88,11 @@ The macros report errors at compile-time using type errors with types of this fo
 `MeaningOfTheTypeParameter` is what the `Integer` in the type parameter means,
 usually which parameter(s) triggered that error.
 
+With the "rust_1_57" feature, the macros instead report errors with const panics,
+whose messages describe the error with the values of the arguments, eg:
+`multindex_mut!(slice; 0, 4..10, 20, 6)` errors with
+``multindex error: argument 1 (`4..10`, covering 4..10) overlaps with argument 3 (`6`, covering 6..7)``.
+
 ### Runtime errors
 
 The macros don't catch all errors at compile-time.
@@ -51,13 +100,13 @@ The macros don't catch all errors at compile-time.
 They always check *at runtime* that the arguments are in bounds of the array or slice,
 with one check.
 
-Once const-generics are stable (and powerful enough to express it generically),
-a new release could be made with macros that check that the arguments are in bounds of arrays,
-at compile-time.
+With the "rust_1_57" feature, the [`multindex_arr`] and [`multindex_arr_mut`] macros
+check that the arguments are in bounds of arrays at compile-time.
 
 # The error types
 
 These are the errors that this macro encodes as types
+(or describes in const panics, with the "rust_1_57" feature)
 
 ### `OverlappingIndexArguments__ArgumentsAre<[(); LeftArgument ], [(); RightArgument ]>`:
 
@@ -72,6 +121,7 @@ Examples:
 
 In `multindex!(slice; 10, 20, 10)`, `LeftArgument` is `0` `RightArgument` is `2`.<br>
 In `multindex!(slice; 0, 4..10, 20, 6)`, `LeftArgument` is `1` `RightArgument` is `3`.<br>
+In `multindex_mut!(slice; end - 4.., end - 1)`, `LeftArgument` is `0` `RightArgument` is `1`.<br>
 
 ### `NextStartIsUnbounded__CurrentArgumentIs<[(); WhichArgument ]>`:
 
@@ -110,6 +160,93 @@ Examples:
 In `multindex!(slice; ..=usize::MAX)`, `WhichArgument` is `0`<br>
 In `multindex!(slice; 1, 3, 5..=usize::MAX)`, `WhichArgument` is `2`.
 
+### `TypedArgumentLength__ArgumentAndTypeSizeAre<[(); WhichArgument ], [(); TypeSize ]>`:
+
+When a typed or cast argument isn't a range as long as the size of its type.
+
+`WhichArgument` is which argument triggers the error, starting at 0.
+
+`TypeSize` is the size of the type of the argument.
+
+Examples:
+
+In `multindex!(slice; u32be @ 0..3)`, `WhichArgument` is `0`, `TypeSize` is `4`<br>
+In `multindex!(slice; 0, u16le @ 1)`, `WhichArgument` is `1`, `TypeSize` is `2`.
+
+### `MismatchedLengths__ArgumentsAre<[(); LeftArgument ], [(); RightArgument ]>`:
+
+When two arguments that must be the same length
+(eg: the arguments of [`multiswap`], or a [`multicopy_within`] pair)
+aren't both indices, or bounded ranges of the same length.
+
+`LeftArgument` and `RightArgument` are the positions of the two arguments, starting at 0.
+
+Examples:
+
+In `multiswap!(slice; 0..4, 8..11)`, `LeftArgument` is `0` `RightArgument` is `1`.<br>
+In `multicopy_within!(slice; 0 => 1, 2..4 => 6..)`, `LeftArgument` is `2` `RightArgument` is `3`.
+
+### `NotAPermutation__ArgumentAndLengthAre<[(); WhichArgument ], [(); Length ]>`:
+
+When an index passed to [`permute`] isn't less than the amount of indices,
+or isn't an index (eg: a range).
+
+`WhichArgument` is which argument triggers the error, starting at 0.
+
+`Length` is the amount of indices.
+
+Examples:
+
+In `permute!(slice; [0, 4, 1, 2])`, `WhichArgument` is `1`, `Length` is `4`<br>
+In `permute!(slice; [1, 0..1])`, `WhichArgument` is `1`, `Length` is `2`.
+
+### `GapBeforeArgument__ArgumentAndExpectedStartAre<[(); WhichArgument ], [(); ExpectedStart ]>`:
+
+When an argument passed to an exhaustive macro (eg: [`multindex_exhaustive_mut`])
+doesn't start where the previous argument ends (or at 0, for the first argument),
+leaving a gap before it.
+This also happens for arguments that aren't in ascending order.
+
+`WhichArgument` is which argument triggers the error, starting at 0.
+
+`ExpectedStart` is the index that the argument must start at.
+
+Examples:
+
+In `multindex_exhaustive_mut!(slice; 1, 2..4)`, `WhichArgument` is `0`, `ExpectedStart` is `0`<br>
+In `multindex_exhaustive_mut!(slice; 0, 1..4, 5)`, `WhichArgument` is `2`, `ExpectedStart` is `4`.
+
+### `RuntimeArgument__CurrentArgumentIs<[(); WhichArgument ]>`:
+
+When a `dyn` argument is passed to a macro that needs to know every argument at compile-time,
+eg: [`multindex_exhaustive_mut`], [`multindex_rest_mut`], [`multiget_each`],
+[`multiget_clamped`], and [`multiswap`].
+Those macros also reject arguments relative to the end of the slice (eg: `end - 4..`),
+while [`try_multindex`] and [`try_multindex_mut`] only reject `dyn` arguments.
+
+`WhichArgument` is which argument triggers the error, starting at 0.
+
+Examples:
+
+In `multindex_rest_mut!(slice; 0, dyn i)`, `WhichArgument` is `1`<br>
+In `multindex_exhaustive_mut!(slice; dyn i, 1..)`, `WhichArgument` is `0`<br>
+In `multiget_each!(slice; 0..2, 2, dyn i)`, `WhichArgument` is `2`<br>
+In `multiswap!(slice; 0..4, end - 4..)`, `WhichArgument` is `1`<br>
+In `try_multindex_mut!(slice; 0..2, dyn i, end - 2)`, `WhichArgument` is `1`.
+
+### `FieldTooWide__ArgumentAndWidthAre<[(); WhichArgument ], [(); Width ]>`:
+
+When a bounded argument of [`bitindex`] is more than 64 bits long.
+
+`WhichArgument` is which argument triggers the error, starting at 0.
+
+`Width` is the amount of bits in the argument.
+
+Examples:
+
+In `bitindex!(bytes; 0..65)`, `WhichArgument` is `0`, `Width` is `65`<br>
+In `bitindex!(bytes; 0, 4.., 100)`, `WhichArgument` is `1`, `Width` is `96`.
+
 
 # Examples
 
@@ -185,8 +322,54 @@ use multindex::multindex_mut;
     assert_eq!(inclusive, (&mut [3, 5, 8, 13, 21],));
 }
 
+{
+    // Index:      0  1  2   3   4   5   6   7    8
+    let mut arr = [3, 5, 8, 13, 21, 34, 55, 89, 144];
+
+    let (start, len) = (5, 3);
+
+    // `dyn` arguments return references to elements (for integers),
+    // and slices (for ranges).
+    let mixed: (&mut [i32; 2], &mut i32, &mut [i32]) =
+        multindex_mut!(arr; 0..2, dyn len, dyn start..start + len);
+    assert_eq!(mixed, (&mut [3, 5], &mut 13, &mut [34, 55, 89][..]));
+}
+
+{
+    // Index:      0  1  2   3   4   5   6   7    8
+    let mut arr = [3, 5, 8, 13, 21, 34, 55, 89, 144];
+
+    // Arguments relative to the end of the slice return references to elements
+    // (for integers), and arrays (for ranges),
+    // the `..` range before them returns a slice.
+    let from_end: (&mut [i32; 2], &mut [i32], &mut [i32; 2], &mut i32) =
+        multindex_mut!(arr; ..2, .., end - 3..end - 1, end - 1);
+    assert_eq!(from_end, (&mut [3, 5], &mut [8, 13, 21, 34][..], &mut [55, 89], &mut 144));
+}
+
 
 ```
 
 
+
+[`multindex_dyn`]: ../macro.multindex_dyn.html
+[`multindex_dyn_mut`]: ../macro.multindex_dyn_mut.html
+[`multiget_dyn`]: ../macro.multiget_dyn.html
+[`multiget_dyn_mut`]: ../macro.multiget_dyn_mut.html
+[`multindex_arr`]: ../macro.multindex_arr.html
+[`generic`]: ../generic/index.html
+[`endian`]: ../endian/index.html
+[`Pod`]: ../pod/trait.Pod.html
+[`multindex_arr_mut`]: ../macro.multindex_arr_mut.html
+[`multiswap`]: ../macro.multiswap.html
+[`multicopy_within`]: ../macro.multicopy_within.html
+[`permute`]: ../macro.permute.html
+[`multindex_exhaustive_mut`]: ../macro.multindex_exhaustive_mut.html
+[`multindex_rest_mut`]: ../macro.multindex_rest_mut.html
+[`multiget_each`]: ../macro.multiget_each.html
+[`multiget_clamped`]: ../macro.multiget_clamped.html
+[`try_multindex`]: ../macro.try_multindex.html
+[`try_multindex_mut`]: ../macro.try_multindex_mut.html
+[`bitindex`]: ../macro.bitindex.html
+
 */
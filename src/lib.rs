@@ -99,6 +99,53 @@ assert_eq!(row3, &[1597, 2584, 4181, 6765, 10946]);
 
 ```
 
+### Byte layouts
+
+This example demonstrates how you can declare named fields in a byte slice,
+which are checked not to overlap at compile-time.
+
+```rust
+multindex::layout! {
+    pub struct Header {
+        magic: 0..4,
+        version: 4,
+        len: 6..8,
+        payload: 8..,
+    }
+}
+
+let mut packet = *b"MAGI\x02\x00\x00\x03abc";
+
+let header = Header::view_mut(&mut packet).unwrap();
+assert_eq!(header.magic, b"MAGI");
+assert_eq!(u16::from_be_bytes(*header.len), 3);
+*header.version += 1;
+
+assert_eq!(Header::view(&packet).unwrap().version, &3);
+assert!(Header::view(&packet[..Header::MIN_LEN - 1]).is_none());
+
+```
+
+### Trailers
+
+This example demonstrates how you can index the end of a variable-length buffer,
+with arguments relative to the end of the slice.
+
+```rust
+use multindex::multindex_mut;
+
+let mut packet = *b"HEADER01payload\xDE\xAD\xBE\xEF";
+
+let (header, body, checksum) = multindex_mut!(packet; ..8, .., end - 4..);
+assert_eq!(header, b"HEADER01");
+assert_eq!(body, b"payload");
+assert_eq!(u32::from_be_bytes(*checksum), 0xDEADBEEF);
+
+*checksum = [0; 4];
+assert_eq!(&packet[8..], b"payload\0\0\0\0");
+
+```
+
 # Minimum Supported Rust Version
 
 This crate requires at least Rust 1.46.0 .
@@ -110,9 +157,30 @@ to check that the indices/ranges passed to the macros don't overlap
 # no-std support
 
 This crate is `#[no-std]`.
-If newer versions add features that require std,
-they'll be conditional on a "std" feature being enabled
-(it won't be enabled by default).
+Features that require std are conditional on the "std" feature being enabled
+(it's not enabled by default).
+
+# Cargo features
+
+- "std": Implements the `std::error::Error` trait for the error types of this crate.
+
+- "rust_1_57": Makes the indexing macros report compile-time errors with
+  const panics that describe the error, instead of with types.
+  Also enables the `multindex_arr`, `multindex_arr_mut`,
+  and `multindex_arr_exhaustive_mut` macros,
+  which check that the arguments are in bounds of arrays at compile-time,
+  the `multisplit` macro, for splitting owned arrays into owned parts,
+  the `multindex_rest_mut` macro, which also returns the parts of the slice between the arguments,
+  and the `generic` module, for indexing with arguments derived from const generic parameters.
+  Requires Rust 1.57.0.
+
+- "rust_1_64": Enables the "rust_1_57" feature,
+  and the `multindex_const` macro, for indexing slices in `const fn`s and constants.
+  Requires Rust 1.64.0.
+
+- "rust_1_77": Enables the "rust_1_64" feature,
+  and the `layout_of` macro, for declaring byte layouts from the fields of `#[repr(C)]` structs.
+  Requires Rust 1.77.0.
 
 
 [shared documentation]: ./indexing_macro_docs/index.html
@@ -125,8 +193,19 @@ they'll be conditional on a "std" feature being enabled
 #[doc(hidden)]
 pub extern crate core;
 
+#[cfg(all(feature = "std", not(any(test, feature = "testing"))))]
+extern crate std;
+
 pub mod indexing_macro_docs;
 
+pub mod endian;
+
+pub mod bits;
+
+pub mod pod;
+
+pub mod cursor;
+
 #[doc(hidden)]
 #[macro_use]
 pub mod macros;
@@ -143,6 +222,23 @@ pub mod index_properties;
 #[doc(hidden)]
 pub mod ptr_indexing;
 
+#[doc(hidden)]
+pub mod dyn_indexing;
+
+#[doc(hidden)]
+#[cfg(feature = "rust_1_57")]
+pub mod array_indexing;
+
+#[cfg(feature = "rust_1_57")]
+pub mod generic;
+
+#[doc(hidden)]
+#[cfg(feature = "rust_1_57")]
+pub mod rest_indexing;
+
+#[cfg(feature = "rust_1_57")]
+mod panic_message;
+
 #[doc(hidden)]
 pub mod std_const_fns;
 
@@ -152,6 +248,9 @@ pub mod are_disjoint;
 #[doc(hidden)]
 pub mod utils;
 
+#[doc(hidden)]
+pub mod writing;
+
 #[doc(hidden)]
 #[cfg(feature = "testing")]
 pub mod test_utils;
@@ -160,28 +259,55 @@ pub mod test_utils;
 #[cfg(feature = "testing")]
 pub mod doc_based_tests;
 
-#[doc(hidden)]
-pub use error::Error;
+pub use crate::{
+    cursor::{Cursor, WriteCursor},
+    error::{Error, OutOfBounds},
+};
 
 #[doc(hidden)]
 pub mod pmr {
     pub use crate::{
         are_disjoint::AreAllDisjoint,
-        error::{ErrorPicker, ErrorTuple, NoErrorsFound},
+        cursor::consumed_len,
+        dyn_indexing::{
+            compute_dyn_index_arguments, compute_mixed_index_arguments, dyn_error_to_out_of_bounds,
+            DynIndexArg, IntoDynIndexArg,
+        },
+        error::{ErrorPicker, ErrorTuple, NoErrorsFound, OutOfBounds},
         index_argument::{
-            IK_Index, IK_Range, IK_RangeFrom, IndexArgument, IndexKind, IndexKindPicker,
-            IntoPrenormIndex, PrenormIndex,
+            ClampedIndexKindPicker, IK_Index, IK_Range, IK_RangeClamped, IK_RangeFrom,
+            IndexArgument, IndexFromEnd, IndexKind, IndexKindPicker, IntoPrenormIndex,
+            PrenormIndex, RangeFromEnd,
         },
         index_properties::{
             ComputedConstants, IndexArgumentStats, IndexArgumentsAndStats, IndexProperties,
         },
+        pod::check_cast_alignment,
         ptr_indexing::{IndexPointer, Indexer, IndexerParams},
         std_const_fns::result_m::is_err,
-        utils::{panic_on_oob_max_index, AssocType, BorrowSelf, SliceParts, SlicePartsMut},
+        utils::{
+            panic_on_dyn_error, panic_on_oob_max_index, permute_in_place, shared_lifetime,
+            AssocType, BorrowSelf, SliceParts, SlicePartsMut,
+        },
+        writing::{copy_from, Fill, Gather, Put, SwapWith},
     };
 
+    #[cfg(feature = "rust_1_57")]
+    pub use crate::{
+        array_indexing::{ArrayCoverage, ArrayIndexer, ArrayOwned, ArrayParts, ArrayPartsMut},
+        rest_indexing::{uncovered_regions, uncovered_slices_mut},
+        utils::const_panic_on_oob,
+    };
+
+    #[cfg(feature = "rust_1_77")]
+    pub use crate::utils::field_size;
+
     pub use core::option::Option::{self, None, Some};
-    pub use core::result::Result::Err;
+    pub use core::result::Result::{Err, Ok};
+
+    pub use core::{compile_error, concat, stringify};
+
+    pub use paste::paste;
 }
 
 #[cfg(all(test, not(feature = "testing")))]
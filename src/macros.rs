@@ -1,18 +1,54 @@
+#[cfg(feature = "rust_1_57")]
+#[macro_use]
+mod array_indexing_macros;
+
+#[macro_use]
+mod bit_indexing_macros;
+
+#[cfg(feature = "rust_1_64")]
+#[macro_use]
+mod const_indexing_macros;
+
 #[macro_use]
 mod construction_macros;
 
 #[macro_use]
 mod control_flow;
 
+#[macro_use]
+mod cursor_macros;
+
+#[macro_use]
+mod dyn_indexing_macros;
+
+#[macro_use]
+mod gather_macros;
+
 #[macro_use]
 mod indexing_macros;
 
+#[macro_use]
+mod layout_macros;
+
+#[macro_use]
+mod mixed_mutability_macros;
+
+#[cfg(feature = "rust_1_57")]
+#[macro_use]
+mod rest_macros;
+
 #[macro_use]
 mod std_functions;
 
+#[macro_use]
+mod swap_macros;
+
 #[macro_use]
 mod type_picking_macros;
 
+#[macro_use]
+mod write_macros;
+
 ////////////////////////////////////////////////////////////////////////////////
 
 #[doc(hidden)]
@@ -0,0 +1,418 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _array_index_impl {
+    (
+        array = $array:expr;
+        indices[];
+        (
+            expected_are_disjoint = $expected_are_disjoint:expr,
+            auto_borrow_method = $auto_borrow_method:ident,
+            array_parts = $array_parts:ident,
+            index_method = $index_method:ident,
+            exhaustive = $exhaustive:tt,
+        )
+    ) => ({
+        use $crate::utils::BorrowSelf as _;
+
+        match $array.$auto_borrow_method() { array => {
+            use $crate::pmr::{ArrayCoverage, $array_parts};
+
+            let $array_parts{ptr, ..} = $array_parts::new(array);
+
+            // Without arguments, only empty arrays are covered.
+            ArrayCoverage::<0>::NEW.assert_reaches_end(ptr);
+        }}
+    });
+    (
+        array = $array:expr;
+        indices[];
+        $params:tt
+    ) => ({
+        let _ = $array;
+        ()
+    });
+    (
+        array = $array:expr;
+        indices[$($index:expr,)+];
+        $params:tt
+    ) => (
+        $crate::_array_index_impl!{
+            @accum
+            $array;
+            []
+            [$($index,)*]
+            [
+                0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+                16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+                32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+                48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+                64
+            ]
+            $params
+        }
+    );
+    (@accum
+        $array:expr;
+        [$($prev:tt)*]
+        [ $index:expr, $($rem_index:tt)*]
+        [ $count:tt $($rem_count:tt)*]
+        $params:tt
+    )=>{
+        $crate::_array_index_impl!{
+            @accum
+            $array;
+            [$($prev)* ($count, $index) ]
+            [$($rem_index)*]
+            [$($rem_count)*]
+            $params
+        }
+    };
+    (@accum
+        $array:expr;
+        [$(($count:tt, $index:expr))*]
+        []
+        [$index_arg_count:tt $($rem_count:tt)*]
+        (
+            expected_are_disjoint = $expected_are_disjoint:expr,
+            auto_borrow_method = $auto_borrow_method:ident,
+            array_parts = $array_parts:ident,
+            index_method = $index_method:ident,
+            $(exhaustive = $exhaustive:tt,)?
+        )
+    )=>({
+        $crate::_declare_comp_consts!{
+            args = [$((static, $index))*],
+            index_arg_count = $index_arg_count,
+            expected_are_disjoint = $expected_are_disjoint,
+            $(exhaustive = $exhaustive,)?
+        }
+
+        use $crate::utils::BorrowSelf as _;
+        // The `*borrow_**` method here ensures that `$array`
+        // is not more layers of mutable references than necessary.
+        //
+        // The match ensures that temporary expressions passed to this macro lives
+        // for the duration of the scope
+        match $array.$auto_borrow_method() { array => unsafe {
+            use $crate::pmr::{ArrayIndexer, IndexArgument, $array_parts};
+
+            // `lifetime` is a `PhantomData<&'a (mut) T>` used to ensure that the
+            // references returned by `ArrayIndexer::index_ptr_*` have the correct lifetime.
+            let $array_parts{ptr, lifetime} = $array_parts::new({array});
+
+            // The exhaustive macros check that the last argument reaches the end of the array,
+            // `check_exhaustive` already checked that there are no gaps before it.
+            $(
+                $crate::_ignore!{$exhaustive}
+                $crate::pmr::ArrayCoverage::<{
+                    __COMP_CONSTS.ind_args[$index_arg_count - 1].saturated_end()
+                }>::NEW.assert_reaches_end(ptr);
+            )?
+
+            // Each `index_ptr*` call checks that its argument is in bounds of the array,
+            // when it's instantiated.
+            ($({
+                const __IND_ARG: &IndexArgument = &__COMP_CONSTS.ind_args[$count];
+
+                ArrayIndexer::<
+                    $crate::index_argument_to_kind_type!(__IND_ARG),
+                    $count,
+                    { __IND_ARG.start },
+                    { __IND_ARG.len_else_zero() },
+                >::NEW.$index_method(ptr, lifetime)
+            },)*)
+        }}
+    });
+    (@accum
+        $array:expr;
+        [$(($count:tt, $index:expr))*]
+        []
+        [$index_arg_count:tt $($rem_count:tt)*]
+        (owned)
+    )=>({
+        $crate::_declare_comp_consts!{
+            args = [$((static, $index))*],
+            index_arg_count = $index_arg_count,
+            expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+        }
+
+        use $crate::pmr::{ArrayIndexer, ArrayOwned, IndexArgument};
+
+        let mut array = ArrayOwned::new($array);
+        let ptr = array.as_ptr();
+
+        unsafe {
+            // Each `read_ptr` call checks that its argument is in bounds of the array,
+            // when it's instantiated.
+            //
+            // The arguments are checked not to overlap,
+            // so every element is moved out at most once.
+            let ret = ($({
+                const __IND_ARG: &IndexArgument = &__COMP_CONSTS.ind_args[$count];
+
+                ArrayIndexer::<
+                    $crate::index_argument_to_kind_type!(__IND_ARG),
+                    $count,
+                    { __IND_ARG.start },
+                    { __IND_ARG.len_else_zero() },
+                >::NEW.read_ptr(ptr)
+            },)*);
+
+            array.drop_uncovered(&__COMP_CONSTS.ind_args);
+
+            ret
+        }
+    });
+}
+
+/// For immutable indexing of arrays with multiple indices/ranges,
+/// checking that they're in bounds at compile-time.
+///
+/// This is like [`multindex`], except that:
+///
+/// - It only takes arrays (or references to them), not slices.
+///
+/// - Out of bounds arguments are compile-time errors, instead of panics.
+///
+/// - The trailing unbounded range (eg: `4..`) returns a `&[T; N - 4]` array,
+///   whose length must be inferred from how it's used (eg: with a type annotation),
+///   and is checked to be `N - 4` at compile-time.
+///
+/// - It doesn't take `dyn` arguments.
+///
+/// The bounds checks happen when the code is compiled to a binary,
+/// so they're not reported by `cargo check`.
+///
+/// This requires the "rust_1_57" feature.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multindex_arr;
+///
+/// let arr = [3u8, 5, 8, 13, 21, 34];
+///
+/// let (first, mid, rest): (&u8, &[u8; 2], &[u8; 3]) = multindex_arr!(arr; 0, 1..3, ..);
+///
+/// assert_eq!(first, &3);
+/// assert_eq!(mid, &[5, 8]);
+/// assert_eq!(rest, &[13, 21, 34]);
+/// ```
+///
+/// This doesn't compile, because the array only has 6 elements.
+///
+/// ```compile_fail
+/// use multindex::multindex_arr;
+///
+/// let arr = [3u8, 5, 8, 13, 21, 34];
+///
+/// let _ = multindex_arr!(arr; 0, 4..8);
+/// ```
+///
+/// [`multindex`]: ./macro.multindex.html
+#[macro_export]
+macro_rules! multindex_arr {
+    ( $array:expr; $($index:expr),* $(,)? ) => (
+        $crate::_array_index_impl!{
+            array = $array;
+            indices[$($index,)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::NO,
+                auto_borrow_method = _11748397628858797803_borrow_self,
+                array_parts = ArrayParts,
+                index_method = index_ptr,
+            )
+        }
+    );
+}
+
+/// For mutable indexing of arrays with multiple indices/ranges,
+/// checking that they're in bounds at compile-time.
+///
+/// This is like [`multindex_mut`], with the differences
+/// described in the docs for [`multindex_arr`].
+///
+/// This requires the "rust_1_57" feature.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multindex_arr_mut;
+///
+/// fn split_header(packet: &mut [u8; 8]) -> (&mut u8, &mut [u8; 3], &mut [u8; 4]) {
+///     multindex_arr_mut!(packet; 0, 1..4, ..)
+/// }
+///
+/// let mut packet = [3u8, 5, 8, 13, 21, 34, 55, 89];
+///
+/// let (kind, len, payload) = split_header(&mut packet);
+/// *kind += 100;
+/// len.reverse();
+/// payload[0] = 0;
+///
+/// assert_eq!(packet, [103, 13, 8, 5, 0, 34, 55, 89]);
+/// ```
+///
+/// This doesn't compile, because the trailing range is an array of 4 elements.
+///
+/// ```compile_fail
+/// use multindex::multindex_arr_mut;
+///
+/// let mut packet = [3u8, 5, 8, 13, 21, 34, 55, 89];
+///
+/// let (_, _): (&mut u8, &mut [u8; 3]) = multindex_arr_mut!(packet; 0, 4..);
+/// ```
+///
+/// [`multindex_mut`]: ./macro.multindex_mut.html
+/// [`multindex_arr`]: ./macro.multindex_arr.html
+#[macro_export]
+macro_rules! multindex_arr_mut {
+    ( $array:expr; $($index:expr),* $(,)? ) => (
+        $crate::_array_index_impl!{
+            array = $array;
+            indices[$($index,)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                array_parts = ArrayPartsMut,
+                index_method = index_ptr_mut,
+            )
+        }
+    );
+}
+
+/// For mutable indexing of arrays with multiple indices/ranges,
+/// that must cover every element of the array.
+///
+/// This is like [`multindex_arr_mut`], except that the arguments are also checked
+/// at compile-time to start at 0, for each one to start where the previous one ends,
+/// and for the last one to reach the end of the array.
+/// Because of that, the arguments must be passed in ascending order.
+///
+/// The check that the arguments reach the end of the array happens
+/// when the code is compiled to a binary, like the bounds checks.
+///
+/// This requires the "rust_1_57" feature.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multindex_arr_exhaustive_mut;
+///
+/// let mut header = [3u8, 5, 8, 13, 21, 34, 55, 89];
+///
+/// let (kind, len, checksum) = multindex_arr_exhaustive_mut!(header; 0, 1..6, 6..8);
+/// *kind += 100;
+/// len.reverse();
+/// *checksum = [0, 0];
+///
+/// assert_eq!(header, [103, 34, 21, 13, 8, 5, 0, 0]);
+/// ```
+///
+/// This doesn't compile, because the last element of the array isn't covered.
+///
+/// ```compile_fail
+/// let mut header = [3u8, 5, 8, 13, 21, 34, 55, 89];
+///
+/// let _ = multindex::multindex_arr_exhaustive_mut!(header; 0, 1..6, 6);
+/// ```
+///
+/// [`multindex_arr_mut`]: ./macro.multindex_arr_mut.html
+#[macro_export]
+macro_rules! multindex_arr_exhaustive_mut {
+    ( $array:expr; $($index:expr),* $(,)? ) => (
+        $crate::_array_index_impl!{
+            array = $array;
+            indices[$($index,)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                array_parts = ArrayPartsMut,
+                index_method = index_ptr_mut,
+                exhaustive = yes,
+            )
+        }
+    );
+}
+
+/// For splitting an owned array into owned elements and sub-arrays,
+/// checking that the arguments are in bounds at compile-time.
+///
+/// This takes the array by value, moving each argument out of it:
+/// indices return a `T`, bounded ranges return a `[T; LEN]`,
+/// and the trailing unbounded range (eg: `4..`) returns a `[T; N - 4]` array,
+/// whose length must be inferred from how it's used (eg: with a type annotation).
+///
+/// The elements that aren't covered by any argument are dropped.
+///
+/// The arguments are checked not to overlap at compile-time,
+/// like the arguments of [`multindex_arr_mut`],
+/// and are otherwise the same as [`multindex_arr`] takes.
+///
+/// This requires the "rust_1_57" feature.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multisplit;
+///
+/// let names = [
+///     "foo".to_string(),
+///     "bar".to_string(),
+///     "baz".to_string(),
+///     "qux".to_string(),
+///     "spam".to_string(),
+/// ];
+///
+/// // The string at index 2 isn't covered by any argument, so it's dropped.
+/// let (first, middle, rest): ([String; 2], String, [String; 1]) =
+///     multisplit!(names; ..2, 3, 4..);
+///
+/// assert_eq!(first, ["foo", "bar"]);
+/// assert_eq!(middle, "qux");
+/// assert_eq!(rest, ["spam"]);
+/// ```
+///
+/// This doesn't compile, because the second and third arguments overlap.
+///
+/// ```compile_fail
+/// let names = ["foo".to_string(), "bar".to_string(), "baz".to_string()];
+/// let _ = multindex::multisplit!(names; 0, 1..3, 2);
+/// ```
+///
+/// [`multindex_arr`]: ./macro.multindex_arr.html
+/// [`multindex_arr_mut`]: ./macro.multindex_arr_mut.html
+#[macro_export]
+macro_rules! multisplit {
+    ( $array:expr; $($index:expr),* $(,)? ) => (
+        $crate::_array_index_impl!{
+            array = $array;
+            indices[$($index,)*];
+            (owned)
+        }
+    );
+}
@@ -0,0 +1,162 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _bit_index_impl {
+    (
+        storage = $storage:expr;
+        indices[];
+    ) => ({
+        let _ = $storage;
+        ()
+    });
+    (
+        storage = $storage:expr;
+        indices[$($index:expr,)+];
+    ) => (
+        $crate::_bit_index_impl!{
+            @accum
+            $storage;
+            []
+            [$($index,)*]
+            [
+                0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+                16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+                32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+                48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+                64
+            ]
+        }
+    );
+    (@accum
+        $storage:expr;
+        [$($prev:tt)*]
+        [ $index:expr, $($rem_index:tt)*]
+        [ $count:tt $($rem_count:tt)*]
+    )=>{
+        $crate::_bit_index_impl!{
+            @accum
+            $storage;
+            [$($prev)* ($count, $index) ]
+            [$($rem_index)*]
+            [$($rem_count)*]
+        }
+    };
+    (@accum
+        $storage:expr;
+        [$(($count:tt, $index:expr))*]
+        []
+        [$index_arg_count:tt $($rem_count:tt)*]
+    )=>({
+        $crate::_declare_comp_consts!{
+            args = [$((static, $index))*],
+            index_arg_count = $index_arg_count,
+            expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+            check = $crate::pmr::IndexArgument::check_field_widths,
+        }
+
+        use $crate::utils::BorrowSelf as _;
+        use $crate::bits::{AsBitStorage as _, BitField, BitStorage};
+
+        // The `borrow_self_mut` method here ensures that `$storage`
+        // is not more layers of mutable references than necessary.
+        //
+        // The match ensures that temporary expressions passed to this macro lives
+        // for the duration of the scope
+        match $storage._11748397628858797803_borrow_self_mut() { storage => {
+            let storage = storage._11748397628858797803_as_bit_storage();
+            let bit_len = BitStorage::bit_len(storage);
+
+            if __COMP_CONSTS.stats.max_bounded_end > bit_len {
+                $crate::pmr::panic_on_oob_max_index(__COMP_CONSTS.stats.max_bounded_end, bit_len);
+            }
+
+            ($(
+                BitField::new(storage, &__COMP_CONSTS.ind_args[$count]),
+            )*)
+        }}
+    });
+}
+
+/// For indexing the bits of an integer or byte slice with multiple indices/ranges,
+/// returning a [`BitField`] for each argument.
+///
+/// This takes an unsigned integer (`u8`, `u16`, `u32`, `u64`, `u128`, `usize`),
+/// or a byte slice/array/`Vec` (fields can span multiple bytes),
+/// which is mutably borrowed by the returned [`BitField`]s.
+///
+/// The arguments are bit positions/ranges, which are checked not to overlap
+/// at compile-time, like the arguments of [`multindex_mut`].
+/// Bounded ranges longer than 64 bits ([`MAX_FIELD_BITS`]) are also
+/// rejected at compile-time.
+/// Single bit positions return one-bit long fields,
+/// and a trailing unbounded range goes up to the last bit of the integer/slice.
+///
+/// Bits are numbered from the least significant bit,
+/// as described in [the `bits` module](./bits/index.html).
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Panics
+///
+/// This macro panics at runtime if the indices/ranges are
+/// out of bounds for the bits of the passed integer/slice,
+/// or if a trailing unbounded range (eg: `8..`) is longer than 64 bits,
+/// since its length depends on the passed integer/slice.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::bitindex;
+///
+/// let mut reg = 0b1_0110_1011u32;
+///
+/// let (mut low, mut flag, mut high) = bitindex!(reg; 0..4, 4, 5..12);
+/// assert_eq!(low.get(), 0b1011);
+/// assert_eq!(flag.get(), 0);
+/// assert_eq!(high.get(), 0b1011);
+///
+/// low.set(0b0101);
+/// flag.set(1);
+/// high.set(0b111_0000);
+///
+/// assert_eq!(reg, 0b1110_0001_0101);
+///
+/// // Fields can span multiple bytes in byte slices.
+/// let mut bytes = [0u8; 3];
+/// let (mut first, mut middle, mut rest) = bitindex!(bytes; ..3, 3..13, ..);
+/// first.set(0b101);
+/// middle.set(0x3FF);
+/// rest.set(0b11);
+///
+/// assert_eq!(bytes, [0b1111_1101, 0b0111_1111, 0]);
+/// ```
+///
+/// This doesn't compile, because the second and third arguments overlap.
+///
+/// ```compile_fail
+/// let mut reg = 0u16;
+/// let _ = multindex::bitindex!(reg; 0..4, 4..8, 7);
+/// ```
+///
+/// This doesn't compile, because the field is longer than 64 bits.
+///
+/// ```compile_fail
+/// let mut bytes = [0u8; 16];
+/// let _ = multindex::bitindex!(bytes; 0..65);
+/// ```
+///
+/// [`BitField`]: ./bits/struct.BitField.html
+/// [`MAX_FIELD_BITS`]: ./bits/constant.MAX_FIELD_BITS.html
+/// [`multindex_mut`]: ./macro.multindex_mut.html
+#[macro_export]
+macro_rules! bitindex {
+    ( $storage:expr; $($index:expr),* $(,)? ) => (
+        $crate::_bit_index_impl!{
+            storage = $storage;
+            indices[$($index,)*];
+        }
+    );
+}
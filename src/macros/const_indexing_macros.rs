@@ -0,0 +1,144 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _const_index_impl {
+    (
+        slice = $slice:expr;
+        indices[];
+    ) => ({
+        let _: &[_] = $slice;
+        ()
+    });
+    (
+        slice = $slice:expr;
+        indices[$($index:expr,)+];
+    ) => (
+        $crate::_const_index_impl!{
+            @accum
+            $slice;
+            []
+            [$($index,)*]
+            [
+                0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+                16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+                32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+                48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+                64
+            ]
+        }
+    );
+    (@accum
+        $slice:expr;
+        [$($prev:tt)*]
+        [ $index:expr, $($rem_index:tt)*]
+        [ $count:tt $($rem_count:tt)*]
+    )=>{
+        $crate::_const_index_impl!{
+            @accum
+            $slice;
+            [$($prev)* ($count, $index) ]
+            [$($rem_index)*]
+            [$($rem_count)*]
+        }
+    };
+    (@accum
+        $slice:expr;
+        [$(($count:tt, $index:expr))*]
+        []
+        [$index_arg_count:tt $($rem_count:tt)*]
+    )=>({
+        $crate::_declare_comp_consts!{
+            args = [$((static, $index))*],
+            index_arg_count = $index_arg_count,
+            expected_are_disjoint = $crate::pmr::AreAllDisjoint::NO,
+        }
+
+        // Trait methods can't be called in const fns,
+        // so this can't auto-borrow the way that the other macros do.
+        let slice: &[_] = $slice;
+
+        if __COMP_CONSTS.stats.max_bounded_end > slice.len() {
+            $crate::pmr::const_panic_on_oob(&__COMP_CONSTS.stats, slice.len())
+        }
+
+        // `lifetime` is a `PhantomData<&'a T>` used to ensure that the
+        // references returned by `Indexer::index_ptr_const` have the correct lifetime.
+        let $crate::pmr::SliceParts{ptr, len, lifetime} = $crate::pmr::SliceParts::new(slice);
+
+        unsafe {
+            ($(
+                $crate::_index_arg_output!{
+                    const,
+                    $count,
+                    ptr = ptr,
+                    len = len,
+                    lifetime = lifetime,
+                },
+            )*)
+        }
+    });
+}
+
+/// For immutable indexing of slices with multiple indices/ranges,
+/// usable in `const fn`s and constants.
+///
+/// This is like [`multindex`], except that:
+///
+/// - It takes a reference to a slice or array (eg: `&'static [T]`, `&[T; N]`),
+///   since it can't auto-borrow its argument.
+///
+/// - It doesn't take `dyn` arguments.
+///
+/// This requires the "rust_1_64" feature.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Panics
+///
+/// This macro panics if the indices/ranges are out of bounds for the passed slice,
+/// which is a compile-time error when it's evaluated in a constant.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multindex_const;
+///
+/// const SQUARES: [u32; 8] = [0, 1, 4, 9, 16, 25, 36, 49];
+///
+/// const PARTS: (&u32, &[u32; 3], &[u32]) = multindex_const!(&SQUARES; 0, 1..=3, ..);
+///
+/// assert_eq!(PARTS, (&0, &[1, 4, 9], &[16, 25, 36, 49][..]));
+///
+/// const fn header(bytes: &[u8]) -> (&[u8; 2], &u8) {
+///     multindex_const!(bytes; ..2, 2)
+/// }
+///
+/// const HEADER: (&[u8; 2], &u8) = header(b"hello");
+///
+/// assert_eq!(HEADER, (b"he", &b'l'));
+///
+/// ```
+///
+/// This doesn't compile, because the indices are out of bounds in a constant.
+///
+/// ```compile_fail
+/// use multindex::multindex_const;
+///
+/// const SQUARES: &[u32] = &[0, 1, 4, 9, 16, 25, 36, 49];
+///
+/// const PARTS: (&u32, &[u32; 8]) = multindex_const!(SQUARES; 0, 1..9);
+/// ```
+///
+/// [`multindex`]: ./macro.multindex.html
+#[macro_export]
+macro_rules! multindex_const {
+    ( $slice:expr; $($index:expr),* $(,)? ) => (
+        $crate::_const_index_impl!{
+            slice = $slice;
+            indices[$($index,)*];
+        }
+    );
+}
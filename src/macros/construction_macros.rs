@@ -21,9 +21,14 @@ macro_rules! new_IndexArgumentsAndStats{
 
         IndexArgumentsAndStats{ $ind_args, stats }
     }};
-    (@from_index_macro; $($index:expr),* $(,)?)=>{{
-        const __PRENORM: &[$crate::pmr::PrenormIndex] =
-            &$crate::prenorm_indices_from!($($index,)*);
+    (@from_index_macro; $($index:expr),* $(,)?)=>{
+        $crate::new_IndexArgumentsAndStats!(
+            @from_prenorm_exprs;
+            $( $crate::pmr::IntoPrenormIndex($index).call(), )*
+        )
+    };
+    (@from_prenorm_exprs; $($prenorm:expr),* $(,)?)=>{{
+        const __PRENORM: &[$crate::pmr::PrenormIndex] = &[$($prenorm,)*];
 
         let mut ind_args = [$crate::pmr::IndexArgument::EMPTY; __PRENORM.len()];
         let mut ret_err = Ok(());
@@ -41,6 +46,7 @@ macro_rules! new_IndexArgumentsAndStats{
         $crate::pmr::ComputedConstants{
             err: ret_err,
             err_tuple: $crate::pmr::ErrorTuple::OK,
+            prenorm: __PRENORM,
             stats: iaas.stats,
             ind_args: iaas.ind_args,
         }
@@ -0,0 +1,219 @@
+/// Reads multiple indices/ranges relative to the position of a [`Cursor`],
+/// advancing the cursor past the end of the furthest argument.
+///
+/// This returns `None`, without advancing the cursor,
+/// if the arguments are out of bounds for the rest of the cursor.
+///
+/// The arguments are the same as [`multiget`] takes
+/// (including `dyn`, typed, and cast arguments),
+/// and a trailing unbounded range consumes the rest of the cursor.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::{take, Cursor};
+///
+/// fn parse_entry<'a>(cursor: &mut Cursor<'a, u8>) -> Option<(u16, &'a [u8])> {
+///     let (kind, len) = take!(*cursor; u16le @ ..2, 2)?;
+///     let len = *len as usize;
+///     let (data,) = take!(*cursor; dyn ..len)?;
+///     Some((kind, data))
+/// }
+///
+/// let bytes = [1, 0, 3, 10, 20, 30, 2, 0, 1, 40];
+/// let mut cursor = Cursor::new(&bytes[..]);
+///
+/// assert_eq!(parse_entry(&mut cursor), Some((1, &[10, 20, 30][..])));
+/// assert_eq!(parse_entry(&mut cursor), Some((2, &[40][..])));
+/// assert_eq!(parse_entry(&mut cursor), None);
+/// assert!(cursor.is_empty());
+///
+/// ```
+///
+/// [`Cursor`]: ./struct.Cursor.html
+/// [`multiget`]: ./macro.multiget.html
+#[macro_export]
+macro_rules! take {
+    ( $cursor:expr; $($args:tt)* ) => ({
+        use $crate::utils::BorrowSelf as _;
+
+        match $cursor._11748397628858797803_borrow_self_mut() { cursor => {
+            let cursor: &mut $crate::Cursor<'_, _> = cursor;
+
+            // `cursor.rest()` returns a reference that doesn't borrow from `cursor`,
+            // which allows advancing the cursor while the returned references are alive.
+            match $crate::_index_impl!{
+                slice = cursor.rest();
+                args[$($args)*];
+                (
+                    expected_are_disjoint = $crate::pmr::AreAllDisjoint::NO,
+                    on_out_of_bounds = with_consumed,
+                    auto_borrow_method = _11748397628858797803_borrow_self,
+                    slice_parts = SliceParts,
+                    index_method = index_ptr,
+                )
+            } {
+                $crate::pmr::Some((ret, consumed)) => {
+                    cursor.advance(consumed);
+                    $crate::pmr::Some(ret)
+                }
+                $crate::pmr::None => $crate::pmr::None,
+            }
+        }}
+    });
+}
+
+/// Reads multiple indices/ranges relative to the position of a [`Cursor`],
+/// without advancing it.
+///
+/// This is equivalent to using [`multiget`] on the rest of the cursor.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::{peek, Cursor};
+///
+/// let bytes = [3, 5, 8, 13, 21];
+/// let cursor = Cursor::new(&bytes[..]);
+///
+/// assert_eq!(peek!(cursor; 0, 2..4), Some((&3, &[8, 13])));
+/// assert_eq!(peek!(cursor; 5), None);
+/// assert_eq!(cursor.position(), 0);
+/// ```
+///
+/// [`Cursor`]: ./struct.Cursor.html
+/// [`multiget`]: ./macro.multiget.html
+#[macro_export]
+macro_rules! peek {
+    ( $cursor:expr; $($args:tt)* ) => ({
+        use $crate::utils::BorrowSelf as _;
+
+        match $cursor._11748397628858797803_borrow_self() { cursor => {
+            let cursor: &$crate::Cursor<'_, _> = cursor;
+            $crate::multiget!(cursor.rest(); $($args)*)
+        }}
+    });
+}
+
+/// Writes values into multiple indices/ranges relative to the position of a [`WriteCursor`],
+/// advancing the cursor past the end of the furthest argument.
+///
+/// Each argument is followed by `=` and the value written into it:
+///
+/// - Integer arguments take an element.
+///
+/// - Range arguments take an array as long as the range.
+///
+/// - Typed arguments (eg: `u32be @ 0..4`) take the integer/float,
+///   or the type in the [`endian`] module.
+///
+/// or by `=>` and a value that every element of the argument is overwritten with,
+/// the same as in [`multiwrite`].
+///
+/// This checks that all arguments are in bounds before writing any value,
+/// returning `None`, without writing or advancing the cursor, if they're out of bounds.
+/// Otherwise, it evaluates and writes the values in order, returning `Some(())`.
+///
+/// The arguments are checked not to overlap at compile-time,
+/// like the arguments of [`multindex_mut`].
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::{put, WriteCursor};
+///
+/// fn write_entry(cursor: &mut WriteCursor<'_, u8>, kind: u16, data: &[u8]) -> Option<()> {
+///     put!(*cursor; u16le @ ..2 = kind, 2 = data.len() as u8)?;
+///     let len = data.len();
+///     cursor.skip(len)?.copy_from_slice(data);
+///     Some(())
+/// }
+///
+/// let mut buffer = [0u8; 10];
+/// let mut cursor = WriteCursor::new(&mut buffer[..]);
+///
+/// assert_eq!(write_entry(&mut cursor, 1, &[10, 20, 30]), Some(()));
+/// assert_eq!(write_entry(&mut cursor, 2, &[40]), Some(()));
+/// assert_eq!(write_entry(&mut cursor, 3, &[50]), None);
+/// assert!(cursor.is_empty());
+///
+/// assert_eq!(buffer, [1, 0, 3, 10, 20, 30, 2, 0, 1, 40]);
+///
+/// ```
+///
+/// This doesn't compile, because the second and third arguments overlap.
+///
+/// ```compile_fail
+/// let mut buffer = [0u8; 10];
+/// let mut cursor = multindex::WriteCursor::new(&mut buffer[..]);
+///
+/// let _ = multindex::put!(cursor; 0 = 1, 1..4 = [2, 3, 4], 3 = 5);
+/// ```
+///
+/// [`WriteCursor`]: ./struct.WriteCursor.html
+/// [`endian`]: ./endian/index.html
+/// [`multindex_mut`]: ./macro.multindex_mut.html
+/// [`multiwrite`]: ./macro.multiwrite.html
+#[macro_export]
+macro_rules! put {
+    ( $cursor:expr; $($args:tt)* ) => (
+        $crate::_parse_write_args!{
+            _put_impl!{($cursor)}
+            []
+            []
+            [$($args)*]
+        }
+    );
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _put_impl {
+    (
+        ($cursor:expr)
+        [$( ($count:tt ($($index:tt)*) $op:tt $value:expr) )*]
+    ) => ({
+        use $crate::utils::BorrowSelf as _;
+
+        match $cursor._11748397628858797803_borrow_self_mut() { cursor => {
+            let cursor: &mut $crate::WriteCursor<'_, _> = cursor;
+
+            match $crate::_index_impl!{
+                slice = cursor.rest_mut();
+                args[$($($index)*,)*];
+                (
+                    expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                    on_out_of_bounds = with_consumed,
+                    auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                    slice_parts = SlicePartsMut,
+                    index_method = index_ptr_mut,
+                )
+            } {
+                $crate::pmr::Some((dests, consumed)) => {
+                    $( $crate::_write_arg!($op, dests.$count, $value); )*
+                    cursor.advance(consumed);
+                    $crate::pmr::Some(())
+                }
+                $crate::pmr::None => $crate::pmr::None,
+            }
+        }}
+    });
+}
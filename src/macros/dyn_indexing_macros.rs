@@ -0,0 +1,321 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _dyn_in_bounds_behavior {
+    (result, $expr:expr) => {
+        $crate::pmr::Ok($expr)
+    };
+    (option, $expr:expr) => {
+        $crate::pmr::Some($expr)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _dyn_on_error {
+    (result, $err:expr) => {
+        $crate::pmr::Err($err)
+    };
+    (option, $err:expr) => {
+        $crate::pmr::None
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _dyn_index_impl {
+    (
+        slice = $slice:expr;
+        indices[];
+        (
+            expected_are_disjoint = $expected_are_disjoint:expr,
+            on_error = $on_error:tt,
+            $($rem_args:tt)*
+        )
+    ) => ({
+        let _ = $slice;
+        // Matching on an error that never happens, so that the error type is inferred.
+        let err: $crate::pmr::Option<$crate::Error> = $crate::pmr::None;
+        match err {
+            $crate::pmr::Some(e) => $crate::_dyn_on_error!($on_error, e),
+            $crate::pmr::None => $crate::_dyn_in_bounds_behavior!($on_error, ()),
+        }
+    });
+    (
+        slice = $slice:expr;
+        indices[$($index:expr,)+];
+        $args:tt
+    ) => (
+        $crate::_dyn_index_impl!{
+            @accum
+            $slice;
+            []
+            [$($index,)*]
+            [
+                0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+                16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+                32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+                48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+                64
+            ]
+            $args
+        }
+    );
+    (@accum
+        $slice:expr;
+        [$($prev:tt)*]
+        [ $index:expr, $($rem_index:tt)*]
+        [ $count:tt $($rem_count:tt)*]
+        $args:tt
+    )=>{
+        $crate::_dyn_index_impl!{
+            @accum
+            $slice;
+            [$($prev)* ($count, $index) ]
+            [$($rem_index)*]
+            [$($rem_count)*]
+            $args
+        }
+    };
+    (@accum
+        $slice:expr;
+        [$(($count:tt, $index:expr))*]
+        []
+        [$index_arg_count:tt $($rem_count:tt)*]
+        (
+            expected_are_disjoint = $expected_are_disjoint:expr,
+            on_error = $on_error:tt,
+            auto_borrow_method = $auto_borrow_method:ident,
+            slice_parts = $slice_parts:ident,
+            index_method = $index_method:ident,
+        )
+    )=>({
+        // The arguments are evaluated before `$slice` is borrowed,
+        // so that they can be computed from the slice.
+        let dyn_args = ($( $crate::pmr::DynIndexArg::new($index), )*);
+
+        use $crate::utils::BorrowSelf as _;
+        // The `*borrow_**` method here ensures that `$slice`
+        // is not more layers of mutable references than necessary.
+        //
+        // The match ensures that temporary expressions passed to this macro lives
+        // for the duration of the scope
+        match $slice.$auto_borrow_method() { slice => {
+            use $crate::pmr::{IndexArgument, IndexPointer, $slice_parts};
+
+            let prenorm = [$( dyn_args.$count.prenorm, )*];
+            let mut ind_args = [IndexArgument::EMPTY; $index_arg_count];
+
+            match $crate::pmr::compute_dyn_index_arguments(
+                &prenorm,
+                &mut ind_args,
+                slice.len(),
+                $expected_are_disjoint,
+            ) {
+                $crate::pmr::Ok(()) => unsafe {
+                    // `lifetime` is a `PhantomData<&'a (mut) T>` used to ensure that the
+                    // reference returned by `IndexPointer::index_ptr_*` has the correct lifetime.
+                    let $slice_parts{ptr, len, lifetime} = $slice_parts::new({slice});
+
+                    let ret = ($(
+                        IndexPointer::$index_method(
+                            dyn_args.$count.indexer(&ind_args[$count], len),
+                            ptr,
+                            lifetime,
+                        ),
+                    )*);
+                    $crate::_dyn_in_bounds_behavior!($on_error, ret)
+                },
+                $crate::pmr::Err(e) => $crate::_dyn_on_error!($on_error, e),
+            }
+        }}
+    });
+}
+
+/// For immutable indexing of slices with multiple runtime indices/ranges.
+///
+/// This is the runtime equivalent of [`multindex`],
+/// returning an `Err` if the indices/ranges are out of bounds for the passed slice,
+/// or if the arguments are invalid.
+///
+/// Integer arguments return references to elements,
+/// range arguments return slices.
+///
+/// # Errors
+///
+/// This returns a [`multindex::Error`](./enum.Error.html):
+///
+/// - `Error::OutOfBounds`: if the indices/ranges are out of bounds for the passed slice.
+///
+/// - Any of the other variants: for the same reasons that the compile-time macros error.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::{multindex_dyn, Error, OutOfBounds};
+///
+/// let arr = [3u8, 5, 8, 13, 21, 34];
+///
+/// let (i, j) = (1, 4);
+///
+/// assert_eq!(multindex_dyn!(arr; i, i..j, j..), Ok((&5, &[5, 8, 13][..], &[21, 34][..])));
+///
+/// assert_eq!(
+///     multindex_dyn!(arr; i, j..10),
+///     Err(Error::OutOfBounds(OutOfBounds{slice_len: 6, max_bounded_end: 10, argument: 1})),
+/// );
+/// ```
+///
+/// [`multindex`]: ./macro.multindex.html
+#[macro_export]
+macro_rules! multindex_dyn {
+    ( $slice:expr; $($index:expr),* $(,)? ) => (
+        $crate::_dyn_index_impl!{
+            slice = $slice;
+            indices[$($index,)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::NO,
+                on_error = result,
+                auto_borrow_method = _11748397628858797803_borrow_self,
+                slice_parts = SliceParts,
+                index_method = index_ptr,
+            )
+        }
+    );
+}
+
+/// For mutable indexing of slices with multiple runtime indices/ranges.
+///
+/// This is the runtime equivalent of [`multindex_mut`],
+/// returning an `Err` if the indices/ranges overlap,
+/// are out of bounds for the passed slice,
+/// or if the arguments are otherwise invalid.
+///
+/// Integer arguments return mutable references to elements,
+/// range arguments return mutable slices.
+///
+/// # Errors
+///
+/// This returns a [`multindex::Error`](./enum.Error.html):
+///
+/// - `Error::OverlappingIndexArgs`: if any argument overlaps with another one.
+///
+/// - `Error::OutOfBounds`: if the indices/ranges are out of bounds for the passed slice.
+///
+/// - Any of the other variants: for the same reasons that the compile-time macros error.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::{multindex_dyn_mut, Error};
+///
+/// let mut arr = [3u8, 5, 8, 13, 21, 34];
+///
+/// for i in 0..2 {
+///     let (first, mid, last) = multindex_dyn_mut!(arr; i, i + 1..i + 3, i + 3).unwrap();
+///     *first += mid[0] + mid[1];
+///     *last += mid[0] + mid[1];
+/// }
+/// assert_eq!(arr, [16, 39, 8, 26, 55, 34]);
+///
+/// assert_eq!(
+///     multindex_dyn_mut!(arr; 0..3, 2),
+///     Err(Error::OverlappingIndexArgs{left: 0, right: 1}),
+/// );
+/// ```
+///
+/// [`multindex_mut`]: ./macro.multindex_mut.html
+#[macro_export]
+macro_rules! multindex_dyn_mut {
+    ( $slice:expr; $($index:expr),* $(,)? ) => (
+        $crate::_dyn_index_impl!{
+            slice = $slice;
+            indices[$($index,)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                on_error = result,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                slice_parts = SlicePartsMut,
+                index_method = index_ptr_mut,
+            )
+        }
+    );
+}
+
+/// For immutable indexing of slices with multiple runtime indices/ranges.
+///
+/// This is the same as [`multindex_dyn`],
+/// except that it returns `None` instead of an error.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multiget_dyn;
+///
+/// let arr = [3u8, 5, 8, 13, 21, 34];
+///
+/// let len = 2;
+///
+/// assert_eq!(multiget_dyn!(arr; ..len, len..len * 2), Some((&[3, 5][..], &[8, 13][..])));
+///
+/// assert_eq!(multiget_dyn!(arr; ..len, len * 4), None);
+/// ```
+///
+/// [`multindex_dyn`]: ./macro.multindex_dyn.html
+#[macro_export]
+macro_rules! multiget_dyn {
+    ( $slice:expr; $($index:expr),* $(,)? ) => (
+        $crate::_dyn_index_impl!{
+            slice = $slice;
+            indices[$($index,)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::NO,
+                on_error = option,
+                auto_borrow_method = _11748397628858797803_borrow_self,
+                slice_parts = SliceParts,
+                index_method = index_ptr,
+            )
+        }
+    );
+}
+
+/// For mutable indexing of slices with multiple runtime indices/ranges.
+///
+/// This is the same as [`multindex_dyn_mut`],
+/// except that it returns `None` instead of an error.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multiget_dyn_mut;
+///
+/// let mut arr = [3u8, 5, 8, 13, 21, 34];
+///
+/// let len = 2;
+///
+/// assert_eq!(
+///     multiget_dyn_mut!(arr; ..len, len..len * 2),
+///     Some((&mut [3, 5][..], &mut [8, 13][..])),
+/// );
+///
+/// assert_eq!(multiget_dyn_mut!(arr; ..len, 1), None);
+///
+/// assert_eq!(multiget_dyn_mut!(arr; ..len, len * 4), None);
+/// ```
+///
+/// [`multindex_dyn_mut`]: ./macro.multindex_dyn_mut.html
+#[macro_export]
+macro_rules! multiget_dyn_mut {
+    ( $slice:expr; $($index:expr),* $(,)? ) => (
+        $crate::_dyn_index_impl!{
+            slice = $slice;
+            indices[$($index,)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                on_error = option,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                slice_parts = SlicePartsMut,
+                index_method = index_ptr_mut,
+            )
+        }
+    );
+}
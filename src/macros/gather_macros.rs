@@ -0,0 +1,358 @@
+/// Splits the comma-separated arguments of the gather/scatter macros,
+/// passing them to `$callback` as `(count value_ident (argument tokens))` tuples.
+///
+/// Each argument is parsed like `_index_impl` does,
+/// so that commas inside of types and expressions (eg: `cast<Pair<A, B>> @ 0..8`)
+/// don't split the argument.
+/// Arguments relative to the end of the slice (eg: `end - 4..`) are passed as tokens,
+/// since they'd be parsed as an expression otherwise.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _split_gather_args {
+    (@start $callback:ident!$prefix:tt [$($args:tt)*]) => {
+        $crate::_split_gather_args!{
+            $callback!$prefix
+            []
+            []
+            [$($args)*]
+            [
+                (0 v0) (1 v1) (2 v2) (3 v3) (4 v4) (5 v5) (6 v6) (7 v7)
+                (8 v8) (9 v9) (10 v10) (11 v11) (12 v12) (13 v13) (14 v14) (15 v15)
+                (16 v16) (17 v17) (18 v18) (19 v19) (20 v20) (21 v21) (22 v22) (23 v23)
+                (24 v24) (25 v25) (26 v26) (27 v27) (28 v28) (29 v29) (30 v30) (31 v31)
+                (32 v32) (33 v33) (34 v34) (35 v35) (36 v36) (37 v37) (38 v38) (39 v39)
+                (40 v40) (41 v41) (42 v42) (43 v43) (44 v44) (45 v45) (46 v46) (47 v47)
+                (48 v48) (49 v49) (50 v50) (51 v51) (52 v52) (53 v53) (54 v54) (55 v55)
+                (56 v56) (57 v57) (58 v58) (59 v59) (60 v60) (61 v61) (62 v62) (63 v63)
+            ]
+        }
+    };
+    (@push
+        $callback:ident!$prefix:tt
+        [$($prev:tt)*]
+        $arg:tt
+        [$($($rem:tt)+)?]
+        [$count_ident:tt $($rem_count:tt)*]
+    ) => {
+        $crate::_split_gather_args!{
+            $callback!$prefix
+            [$($prev)* ($count_ident $arg)]
+            []
+            [$($($rem)*)?]
+            [$($rem_count)*]
+        }
+    };
+    // The tokens of an argument relative to the end of the slice
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        [$($curr:tt)+]
+        [, $($rem:tt)*]
+        $counts:tt
+    ) => {
+        $crate::_split_gather_args!{
+            @push $callback!$prefix $prev ($($curr)*) [$($rem)*] $counts
+        }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        [$($curr:tt)+]
+        []
+        $counts:tt
+    ) => {
+        $crate::_split_gather_args!{
+            @push $callback!$prefix $prev ($($curr)*) [] $counts
+        }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        [$($curr:tt)+]
+        [$token:tt $($rem:tt)*]
+        $counts:tt
+    ) => {
+        $crate::_split_gather_args!{
+            $callback!$prefix
+            $prev
+            [$($curr)* $token]
+            [$($rem)*]
+            $counts
+        }
+    };
+    (
+        $callback:ident!{$($prefix:tt)*}
+        [$( (($count:tt $ident:ident) $args:tt) )*]
+        []
+        []
+        $counts:tt
+    ) => {
+        $crate::$callback!{$($prefix)* [$(($count $ident $args))*]}
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        []
+        [end - $($rem:tt)*]
+        $counts:tt
+    ) => {
+        $crate::_split_gather_args!{
+            $callback!$prefix
+            $prev
+            [end -]
+            [$($rem)*]
+            $counts
+        }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        []
+        [dyn $index:expr $(, $($rem:tt)*)?]
+        $counts:tt
+    ) => {
+        $crate::_split_gather_args!{
+            @push $callback!$prefix $prev (dyn $index) [$($($rem)*)?] $counts
+        }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        []
+        [cast < $ty:ty > @ $index:expr $(, $($rem:tt)*)?]
+        $counts:tt
+    ) => {
+        $crate::_split_gather_args!{
+            @push $callback!$prefix $prev (cast < $ty > @ $index) [$($($rem)*)?] $counts
+        }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        []
+        [$ty:ident @ $index:expr $(, $($rem:tt)*)?]
+        $counts:tt
+    ) => {
+        $crate::_split_gather_args!{
+            @push $callback!$prefix $prev ($ty @ $index) [$($($rem)*)?] $counts
+        }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        []
+        [$index:expr $(, $($rem:tt)*)?]
+        $counts:tt
+    ) => {
+        $crate::_split_gather_args!{
+            @push $callback!$prefix $prev ($index) [$($($rem)*)?] $counts
+        }
+    };
+}
+
+/// For cloning the values at multiple indices/ranges of a slice.
+///
+/// This takes the same arguments as [`multindex`],
+/// returning a tuple with a clone of each element and array,
+/// and the value of each typed argument (eg: `u32be @ 0..4`).
+///
+/// If the arguments are surrounded by `[]`, this returns an array instead,
+/// which requires every argument to produce the same type
+/// (eg: `gather!(slice; [3, 1, 0])` returns a `[T; 3]`).
+///
+/// Trailing unbounded ranges (eg: `3..`) aren't supported,
+/// since they can't be cloned into an owned value.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Panics
+///
+/// This macro panics at runtime if the indices/ranges are
+/// out of bounds for the passed slice.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::gather;
+///
+/// let arr = [3, 5, 8, 13, 21, 34];
+///
+/// let (a, b, c): (u32, u32, [u32; 2]) = gather!(arr; 3, 1, 0..2);
+/// assert_eq!((a, b, c), (13, 5, [3, 5]));
+///
+/// let kernel: [u32; 3] = gather!(arr; [5, 1, 5]);
+/// assert_eq!(kernel, [34, 5, 34]);
+///
+/// let bytes = [0u8, 1, 2, 3];
+/// assert_eq!(gather!(bytes; u16be @ 0..2, u16le @ 2..4), (1, 0x0302));
+/// ```
+///
+/// [`multindex`]: ./macro.multindex.html
+#[macro_export]
+macro_rules! gather {
+    ( $slice:expr; [$($args:tt)*] $(,)? ) => (
+        $crate::_split_gather_args!{
+            @start _gather_impl!{($slice) array} [$($args)*]
+        }
+    );
+    ( $slice:expr; $($args:tt)* ) => (
+        $crate::_split_gather_args!{
+            @start _gather_impl!{($slice) tuple} [$($args)*]
+        }
+    );
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _gather_impl {
+    (
+        ($slice:expr)
+        $form:ident
+        [$( ($count:tt $ident:ident ($($index:tt)*)) )*]
+    ) => ({
+        let refs = $crate::_index_impl!{
+            slice = $slice;
+            args[$($($index)*,)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::NO,
+                on_out_of_bounds = panic,
+                auto_borrow_method = _11748397628858797803_borrow_self,
+                slice_parts = SliceParts,
+                index_method = index_ptr,
+            )
+        };
+        $crate::_gather_output!{
+            $form [$( $crate::pmr::Gather::gather(refs.$count), )*]
+        }
+    });
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _gather_output {
+    (array [$($value:expr,)*]) => {
+        [$($value,)*]
+    };
+    (tuple [$($value:expr,)*]) => {
+        ($($value,)*)
+    };
+}
+
+/// For writing values into multiple indices/ranges of a slice.
+///
+/// This takes the same arguments as [`multindex_mut`], followed by `<=`
+/// and a tuple with the values for each argument
+/// (the same types as [`gather`] returns).
+///
+/// If the arguments are surrounded by `[]`, this takes an array instead,
+/// which requires every argument to take the same type
+/// (eg: `scatter!(slice; [3, 1, 0] <= [a, b, c])`).
+///
+/// The arguments are checked not to overlap at compile-time,
+/// like the arguments of [`multindex_mut`].
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Panics
+///
+/// This macro panics at runtime if the indices/ranges are
+/// out of bounds for the passed slice.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::{gather, scatter};
+///
+/// let mut arr = [3, 5, 8, 13, 21, 34];
+///
+/// let (a, b, c) = gather!(arr; 3, 1, 0..2);
+/// scatter!(arr; 3, 1, 4..6 <= (a * 2, b * 2, c));
+/// assert_eq!(arr, [3, 10, 8, 26, 3, 5]);
+///
+/// scatter!(arr; [5, 0, 2] <= [1, 2, 3]);
+/// assert_eq!(arr, [2, 10, 3, 26, 3, 1]);
+///
+/// let mut bytes = [0u8; 4];
+/// scatter!(bytes; u16be @ 0..2, u16le @ 2..4 <= (1, 0x0302));
+/// assert_eq!(bytes, [0, 1, 2, 3]);
+/// ```
+///
+/// This doesn't compile, because `3` is written to twice.
+///
+/// ```compile_fail
+/// let mut arr = [3, 5, 8, 13, 21, 34];
+/// multindex::scatter!(arr; 3, 1, 3 <= (0, 0, 0));
+/// ```
+///
+/// [`gather`]: ./macro.gather.html
+/// [`multindex_mut`]: ./macro.multindex_mut.html
+#[macro_export]
+macro_rules! scatter {
+    ( $slice:expr; [$($args:tt)*] <= $values:expr $(,)? ) => (
+        $crate::_split_gather_args!{
+            @start _scatter_impl!{($slice) ($values) array} [$($args)*]
+        }
+    );
+    ( $slice:expr; $($rem:tt)* ) => (
+        $crate::_scatter_impl!{
+            @split ($slice) [] [$($rem)*]
+        }
+    );
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _scatter_impl {
+    (@split ($slice:expr) [$($args:tt)*] [<= $values:expr $(,)?]) => {
+        $crate::_split_gather_args!{
+            @start _scatter_impl!{($slice) ($values) tuple} [$($args)*]
+        }
+    };
+    (@split $slice:tt [$($args:tt)*] [$token:tt $($rem:tt)*]) => {
+        $crate::_scatter_impl!{
+            @split $slice [$($args)* $token] [$($rem)*]
+        }
+    };
+    (@split $slice:tt $args:tt []) => {
+        $crate::pmr::compile_error!{"expected `<= values` after the arguments"}
+    };
+    (
+        ($slice:expr)
+        ($values:expr)
+        $form:ident
+        [$( ($count:tt $ident:ident ($($index:tt)*)) )*]
+    ) => ({
+        let dests = $crate::_index_impl!{
+            slice = $slice;
+            args[$($($index)*,)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                on_out_of_bounds = panic,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                slice_parts = SlicePartsMut,
+                index_method = index_ptr_mut,
+            )
+        };
+        let $crate::_scatter_pattern!($form [$($ident,)*]) = $values;
+        $( $crate::pmr::Put::put(dests.$count, $ident); )*
+    });
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _scatter_pattern {
+    (array [$($ident:ident,)*]) => {
+        [$($ident,)*]
+    };
+    (tuple [$($ident:ident,)*]) => {
+        ($($ident,)*)
+    };
+}
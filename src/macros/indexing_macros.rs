@@ -1,12 +1,63 @@
 #[doc(hidden)]
 #[macro_export]
 macro_rules! _in_bounds_behavior {
-    (option, $expr:expr) => {
+    (option, $expr:expr, consumed = $consumed:expr $(,)?) => {
         $crate::pmr::Some($expr)
     };
-    (panic, $expr:expr) => {
+    (panic, $expr:expr, consumed = $consumed:expr $(,)?) => {
         $expr
     };
+    (result, $expr:expr, consumed = $consumed:expr $(,)?) => {
+        $crate::pmr::Ok($expr)
+    };
+    (with_consumed, $expr:expr, consumed = $consumed:expr $(,)?) => {
+        $crate::pmr::Some(($expr, $consumed))
+    };
+    (each, $expr:expr, consumed = $consumed:expr $(,)?) => {
+        $expr
+    };
+    (clamped, $expr:expr, consumed = $consumed:expr $(,)?) => {
+        $crate::pmr::Some($expr)
+    };
+}
+
+/// The minimum length of the slice for any argument to be returned,
+/// in `each` mode every argument is bounds checked by `_index_arg_output_in_mode`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _required_len {
+    (each, ind_stats = $ind_stats:expr) => {
+        0
+    };
+    ($on_out_of_bounds:tt, ind_stats = $ind_stats:expr) => {
+        $ind_stats.max_bounded_end
+    };
+}
+
+/// The output of an argument, depending on the `on_out_of_bounds` mode.
+///
+/// In `each` mode the output of each argument is wrapped in an `Option`,
+/// `Some` if that argument is in bounds of the slice.
+///
+/// In `clamped` mode the static ranges are clamped to the length of the slice.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _index_arg_output_in_mode {
+    (each, $kind:tt, $count:tt, ptr = $ptr:ident, len = $len:ident, $($rem:tt)*) => {
+        if __COMP_CONSTS.ind_args[$count].bounded_end() <= $len {
+            $crate::pmr::Some($crate::_index_arg_output!{
+                $kind, $count, ptr = $ptr, len = $len, $($rem)*
+            })
+        } else {
+            $crate::pmr::None
+        }
+    };
+    (clamped, static, $($rem:tt)*) => {
+        $crate::_index_arg_output!{clamped_static, $($rem)*}
+    };
+    ($on_out_of_bounds:tt, $($rem:tt)*) => {
+        $crate::_index_arg_output!{$($rem)*}
+    };
 }
 
 #[doc(hidden)]
@@ -18,6 +69,45 @@ macro_rules! _on_out_of_bounds {
     (option, $($anything:tt)*) => {
         $crate::pmr::None
     };
+    (with_consumed, $($anything:tt)*) => {
+        $crate::pmr::None
+    };
+    (each, $($anything:tt)*) => {
+        $crate::core::unreachable!()
+    };
+    (clamped, $($anything:tt)*) => {
+        $crate::pmr::None
+    };
+    (result, ind_stats = $ind_stats:expr, slice = $slice:ident) => {
+        $crate::pmr::Err($crate::pmr::OutOfBounds {
+            slice_len: $slice.len(),
+            max_bounded_end: $ind_stats.max_bounded_end,
+            argument: $ind_stats.max_bounded_end_arg,
+        })
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _on_dyn_error {
+    // The macros that reject runtime arguments at compile-time (eg: `multiget_each`)
+    // still expand the code that checks them when they're passed,
+    // this keeps the `RuntimeArgument` error as the only one.
+    ($on_out_of_bounds:ident, no_runtime = yes, $err:expr) => {
+        $crate::pmr::panic_on_dyn_error($err)
+    };
+    (panic, $err:expr) => {
+        $crate::pmr::panic_on_dyn_error($err)
+    };
+    (option, $err:expr) => {
+        $crate::pmr::None
+    };
+    (with_consumed, $err:expr) => {
+        $crate::pmr::None
+    };
+    (result, $err:expr) => {
+        $crate::pmr::Err($crate::pmr::dyn_error_to_out_of_bounds($err))
+    };
 }
 
 #[doc(hidden)]
@@ -25,22 +115,23 @@ macro_rules! _on_out_of_bounds {
 macro_rules! _index_impl {
     (
         slice = $slice:expr;
-        indices[];
-        $args:tt
+        args[];
+        $params:tt
     ) => ({
         let _ = $slice;
         ()
     });
     (
         slice = $slice:expr;
-        indices[$($index:expr,)+];
-        $args:tt
+        args[$($args:tt)+];
+        $params:tt
     ) => (
         $crate::_index_impl!{
             @accum
             $slice;
             []
-            [$($index,)*]
+            []
+            [$($args)*]
             [
                 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
                 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
@@ -48,28 +139,128 @@ macro_rules! _index_impl {
                 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
                 64
             ]
-            $args
+            $params
         }
     );
     (@accum
         $slice:expr;
         [$($prev:tt)*]
-        [ $index:expr, $($rem_index:tt)*]
+        [$($dyn_count:tt)*]
+        [ dyn $index:expr $(, $($rem_args:tt)*)?]
         [ $count:tt $($rem_count:tt)*]
-        $args:tt
+        $params:tt
     )=>{
         $crate::_index_impl!{
             @accum
             $slice;
-            [$($prev)* ($count, $index) ]
-            [$($rem_index)*]
+            [$($prev)* ($count, dyn, $index) ]
+            [$($dyn_count)* $count]
+            [$($($rem_args)*)?]
             [$($rem_count)*]
-            $args
+            $params
         }
     };
     (@accum
         $slice:expr;
-        [$(($count:tt, $index:expr))*]
+        [$($prev:tt)*]
+        [$($dyn_count:tt)*]
+        [ end - $start:tt .. $(end $(- $end:tt)?)? $(, $($rem_args:tt)*)?]
+        [ $count:tt $($rem_count:tt)*]
+        $params:tt
+    )=>{
+        $crate::_index_impl!{
+            @accum
+            $slice;
+            [
+                $($prev)*
+                (
+                    $count,
+                    (from_end range),
+                    $crate::pmr::RangeFromEnd{ start: $start, end: 0 $($(+ $end)?)? }
+                )
+            ]
+            [$($dyn_count)* $count]
+            [$($($rem_args)*)?]
+            [$($rem_count)*]
+            $params
+        }
+    };
+    (@accum
+        $slice:expr;
+        [$($prev:tt)*]
+        [$($dyn_count:tt)*]
+        [ end - $start:tt $(, $($rem_args:tt)*)?]
+        [ $count:tt $($rem_count:tt)*]
+        $params:tt
+    )=>{
+        $crate::_index_impl!{
+            @accum
+            $slice;
+            [$($prev)* ($count, (from_end index), $crate::pmr::IndexFromEnd($start)) ]
+            [$($dyn_count)* $count]
+            [$($($rem_args)*)?]
+            [$($rem_count)*]
+            $params
+        }
+    };
+    (@accum
+        $slice:expr;
+        [$($prev:tt)*]
+        $dyn_counts:tt
+        [ $ty:ident @ $index:expr $(, $($rem_args:tt)*)?]
+        [ $count:tt $($rem_count:tt)*]
+        $params:tt
+    )=>{
+        $crate::_index_impl!{
+            @accum
+            $slice;
+            [$($prev)* ($count, (typed $ty), $index) ]
+            $dyn_counts
+            [$($($rem_args)*)?]
+            [$($rem_count)*]
+            $params
+        }
+    };
+    (@accum
+        $slice:expr;
+        [$($prev:tt)*]
+        $dyn_counts:tt
+        [ cast < $ty:ty > @ $index:expr $(, $($rem_args:tt)*)?]
+        [ $count:tt $($rem_count:tt)*]
+        $params:tt
+    )=>{
+        $crate::_index_impl!{
+            @accum
+            $slice;
+            [$($prev)* ($count, (cast $ty), $index) ]
+            $dyn_counts
+            [$($($rem_args)*)?]
+            [$($rem_count)*]
+            $params
+        }
+    };
+    (@accum
+        $slice:expr;
+        [$($prev:tt)*]
+        $dyn_counts:tt
+        [ $index:expr $(, $($rem_args:tt)*)?]
+        [ $count:tt $($rem_count:tt)*]
+        $params:tt
+    )=>{
+        $crate::_index_impl!{
+            @accum
+            $slice;
+            [$($prev)* ($count, static, $index) ]
+            $dyn_counts
+            [$($($rem_args)*)?]
+            [$($rem_count)*]
+            $params
+        }
+    };
+    (@accum
+        $slice:expr;
+        [$(($count:tt, $kind:tt, $index:expr))*]
+        [$($dyn_count:tt)*]
         []
         [$index_arg_count:tt $($rem_count:tt)*]
         (
@@ -78,14 +269,130 @@ macro_rules! _index_impl {
             auto_borrow_method = $auto_borrow_method:ident,
             slice_parts = $slice_parts:ident,
             index_method = $index_method:ident,
+            $(same_lengths = $same_lengths:tt,)?
+            $(exhaustive = $exhaustive:tt,)?
+            $(rest = $rest:tt,)?
+            $(no_runtime = $no_runtime:tt,)?
+            $(no_dyn = $no_dyn:tt,)?
+            $(clamped = $clamped:tt,)?
         )
     )=>({
+        $crate::_declare_comp_consts!{
+            args = [$(($kind, $index))*],
+            index_arg_count = $index_arg_count,
+            expected_are_disjoint = $expected_are_disjoint,
+            $(same_lengths = $same_lengths,)?
+            $(exhaustive = $exhaustive,)?
+            $(no_runtime = $no_runtime,)?
+            $(no_dyn = $no_dyn,)?
+            $(clamped = $clamped,)?
+        }
+
+        // The runtime arguments are evaluated before `$slice` is borrowed,
+        // so that they can be computed from the slice.
+        $crate::_declare_dyn_args!{
+            [$($dyn_count)*]
+            dyn_args = ($( $crate::_index_arg_dyn_value!($kind, $index), )*)
+        }
+
+        use $crate::utils::BorrowSelf as _;
+        // The `*borrow_**` method here ensures that `$slice`
+        // is not more layers of mutable references than necessary.
+        //
+        // The match ensures that temporary expressions passed to this macro lives
+        // for the duration of the scope
+        match $slice.$auto_borrow_method() { slice => unsafe{
+            use $crate::pmr::$slice_parts;
+
+            if $crate::_required_len!($on_out_of_bounds, ind_stats = __COMP_CONSTS.stats)
+                > slice.len()
+            {
+                $crate::_on_out_of_bounds!(
+                    $on_out_of_bounds,
+                    ind_stats = __COMP_CONSTS.stats,
+                    slice = slice
+                )
+            } else {
+                $crate::_check_dyn_args!{
+                    [$($dyn_count)*]
+                    dyn_args = dyn_args,
+                    dyn_ind_args = dyn_ind_args,
+                    slice = slice,
+                    expected_are_disjoint = $expected_are_disjoint,
+                    on_error(|e| $crate::_on_dyn_error!(
+                        $on_out_of_bounds, $(no_runtime = $no_runtime,)? e
+                    )),
+                    then = {
+                        // `lifetime` is a `PhantomData<&'a (mut) T>` used to ensure that the
+                        // reference returned by `IndexPointer::index_ptr_*` has the correct
+                        // lifetime.
+                        let $slice_parts{ptr, len, lifetime} = $slice_parts::new({slice});
+
+                        let ret = ($(
+                            $crate::_index_arg_output_in_mode!{
+                                $on_out_of_bounds,
+                                $kind,
+                                $count,
+                                ptr = ptr,
+                                len = len,
+                                lifetime = lifetime,
+                                dyn_args = dyn_args,
+                                dyn_ind_args = dyn_ind_args,
+                                index_method = $index_method,
+                            },
+                        )*);
+                        // `multindex_rest_mut` also returns the parts of the slice
+                        // that aren't covered by any argument.
+                        $(
+                            let ret = (ret, $crate::_uncovered_slices!{
+                                $rest,
+                                ptr = ptr,
+                                len = len,
+                                lifetime = lifetime,
+                            });
+                        )?
+                        $crate::_in_bounds_behavior!(
+                            $on_out_of_bounds,
+                            ret,
+                            consumed = $crate::_consumed_len!(
+                                [$($dyn_count)*],
+                                dyn_ind_args = dyn_ind_args,
+                                slice_len = len,
+                            ),
+                        )
+                    }
+                }
+            }
+
+        }}
+
+    });
+
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_comp_consts {
+    (
+        args = [$(($kind:tt, $index:expr))*],
+        index_arg_count = $index_arg_count:tt,
+        expected_are_disjoint = $expected_are_disjoint:expr,
+        $(same_lengths = $same_lengths:tt,)?
+        $(exhaustive = $exhaustive:tt,)?
+        $(no_runtime = $no_runtime:tt,)?
+        $(no_dyn = $no_dyn:tt,)?
+        $(clamped = $clamped:tt,)?
+        $(check = $check:path,)?
+    ) => {
         const __COMP_CONSTS: &$crate::pmr::ComputedConstants<
             [$crate::pmr::IndexArgument; $index_arg_count]
         > = {
             let mut comp_consts;
             $crate::block!{'constant:
-                comp_consts = $crate::new_IndexArgumentsAndStats!(@from_index_macro; $($index,)*);
+                comp_consts = $crate::new_IndexArgumentsAndStats!(
+                    @from_prenorm_exprs;
+                    $( $crate::_index_arg_prenorm!($kind, $index), )*
+                );
                 if $crate::pmr::is_err(&comp_consts.err) { break 'constant; }
 
                 let props = $crate::pmr::IndexProperties::new(
@@ -101,63 +408,432 @@ macro_rules! _index_impl {
                 // skips the are_disjoint checks, always returning `AreAllDisjoint::No`.
                 comp_consts.err = props.are_disjoint.check_is_expected(&$expected_are_disjoint);
                 if $crate::pmr::is_err(&comp_consts.err) { break 'constant; }
+
+                // The arguments relative to the end of the slice are normalized at runtime,
+                // this checks the overlaps that don't depend on the length of the slice.
+                comp_consts.err = $crate::pmr::IndexArgument::check_from_end_disjoint(
+                    comp_consts.prenorm,
+                    &comp_consts.ind_args,
+                    &$expected_are_disjoint,
+                );
+                if $crate::pmr::is_err(&comp_consts.err) { break 'constant; }
+
+                let type_sizes: &[$crate::pmr::Option<usize>] =
+                    &[$( $crate::_index_arg_type_size!($kind), )*];
+
+                // The typed arguments (eg: `u32be @ 0..4`) are read from
+                // an `IK_Range` indexer, so their length is checked here.
+                comp_consts.err = $crate::pmr::IndexArgument::check_type_sizes(
+                    &comp_consts.ind_args,
+                    type_sizes,
+                );
+                if $crate::pmr::is_err(&comp_consts.err) { break 'constant; }
+
+                // Whether the arguments must cover every element up to the end of the last one,
+                // eg: in `multindex_exhaustive_mut`.
+                $(
+                    $crate::_ignore!{$exhaustive}
+                    comp_consts.err = $crate::pmr::IndexArgument::check_exhaustive(
+                        comp_consts.prenorm,
+                        &comp_consts.ind_args,
+                    );
+                    if $crate::pmr::is_err(&comp_consts.err) { break 'constant; }
+                )?
+
+                // Whether the arguments must all be known at compile-time,
+                // eg: in `multindex_rest_mut`.
+                $(
+                    $crate::_ignore!{$no_runtime}
+                    comp_consts.err = $crate::pmr::IndexArgument::check_no_runtime(
+                        comp_consts.prenorm,
+                    );
+                    if $crate::pmr::is_err(&comp_consts.err) { break 'constant; }
+                )?
+
+                // Whether `dyn` arguments are rejected, eg: in `try_multindex_mut`,
+                // which can only report that the slice is too short.
+                $(
+                    $crate::_ignore!{$no_dyn}
+                    comp_consts.err = $crate::pmr::IndexArgument::check_no_dyn(
+                        comp_consts.prenorm,
+                    );
+                    if $crate::pmr::is_err(&comp_consts.err) { break 'constant; }
+                )?
+
+                // The pairs of arguments that must have the same length,
+                // eg: the arguments of `multiswap`.
+                // This is after the `no_runtime` check because the lengths of
+                // `dyn` and end-relative arguments are only known at runtime.
+                $(
+                    comp_consts.err = $crate::pmr::IndexArgument::check_same_lengths(
+                        &comp_consts.ind_args,
+                        &$same_lengths,
+                    );
+                    if $crate::pmr::is_err(&comp_consts.err) { break 'constant; }
+                )?
+
+                // Whether ranges are clamped to the length of the slice,
+                // eg: in `multiget_clamped`, where only indices and typed arguments
+                // need to be in bounds.
+                $(
+                    $crate::_ignore!{$clamped}
+                    comp_consts.stats = $crate::pmr::IndexArgument::clamped_stats(
+                        &comp_consts.ind_args,
+                        type_sizes,
+                        comp_consts.stats,
+                    );
+                )?
+
+                // Additional checks specific to the macro, eg: `permute`.
+                $(
+                    comp_consts.err = $check(&comp_consts.ind_args);
+                    if $crate::pmr::is_err(&comp_consts.err) { break 'constant; }
+                )?
             }
-            comp_consts.err_tuple = $crate::error::result_to_tuple(comp_consts.err);
+            // With the "rust_1_57" feature, this panics with a message describing the error.
+            comp_consts.err_tuple = $crate::error::check_result(
+                comp_consts.err,
+                comp_consts.prenorm,
+                &comp_consts.ind_args,
+            );
             &{comp_consts}
         };
 
         const _: $crate::pmr::NoErrorsFound =
             <$crate::error_tuple_to_error_type!(__COMP_CONSTS.err_tuple)>::NEW;
+    };
+}
 
-        use $crate::utils::BorrowSelf as _;
-        // The `*borrow_**` method here ensures that `$slice`
-        // is not more layers of mutable references than necessary.
-        //
-        // The match ensures that temporary expressions passed to this macro lives
-        // for the duration of the scope
-        match $slice.$auto_borrow_method() { slice => unsafe{
-            use $crate::pmr::{
-                Indexer, IndexerParams, IndexArgument,
-                IndexPointer, $slice_parts,
-            };
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _index_arg_prenorm {
+    (static, $index:expr) => {
+        $crate::pmr::IntoPrenormIndex($index).call()
+    };
+    ((typed $ty:ident), $index:expr) => {
+        $crate::pmr::IntoPrenormIndex($index).call()
+    };
+    ((cast $ty:ty), $index:expr) => {
+        $crate::pmr::IntoPrenormIndex($index).call()
+    };
+    (dyn, $index:expr) => {
+        $crate::pmr::PrenormIndex::Runtime
+    };
+    ((from_end $kind:ident), $index:expr) => {
+        $crate::pmr::IntoPrenormIndex($index).call()
+    };
+}
 
-            if __COMP_CONSTS.stats.max_bounded_end > slice.len() {
-                $crate::_on_out_of_bounds!(
-                    $on_out_of_bounds,
-                    ind_stats = __COMP_CONSTS.stats,
-                    slice = slice
-                )
-            } else {
-                // `lifetime` is a `PhantomData<&'a (mut) T>` used to ensure that the
-                // reference returned by `IndexPointer::index_ptr_*` has the correct lifetime.
-                let $slice_parts{ptr, len, lifetime} = $slice_parts::new({slice});
-
-                let ret = ($(
-                    {
-                        const __IND_ARG: &IndexArgument = &__COMP_CONSTS.ind_args[$count];
-
-                        type __IndexerAlias<T> = Indexer<
-                            T,
-                            [T; __IND_ARG.len_else_zero()],
-                            $crate::index_argument_to_kind_type!(__IND_ARG),
-                        >;
-
-                        let caster: __IndexerAlias<_> =
-                            IndexerParams{
-                                index: __IND_ARG.start as _,
-                                slice_len: len,
-                            }.build();
-
-                        IndexPointer::$index_method(caster, ptr, lifetime)
-                    },
-                )*);
-                $crate::_in_bounds_behavior!($on_out_of_bounds, ret )
-            }
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _index_arg_dyn_value {
+    (static, $index:expr) => {
+        ()
+    };
+    ((typed $ty:ident), $index:expr) => {
+        ()
+    };
+    ((cast $ty:ty), $index:expr) => {
+        ()
+    };
+    (dyn, $index:expr) => {
+        $crate::pmr::DynIndexArg::new($index)
+    };
+    ((from_end $kind:ident), $index:expr) => {
+        $crate::pmr::DynIndexArg::new($index)
+    };
+}
 
-        }}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _index_arg_type_size {
+    ((typed $ty:ident)) => {
+        $crate::pmr::Some($crate::endian::$ty::SIZE)
+    };
+    ((cast $ty:ty)) => {
+        $crate::pmr::Some($crate::core::mem::size_of::<$ty>())
+    };
+    ((from_end $kind:ident)) => {
+        $crate::pmr::None
+    };
+    ($kind:ident) => {
+        $crate::pmr::None
+    };
+}
 
-    });
+/// The amount of elements up to the end of the furthest argument,
+/// which `take` and `put` advance cursors by.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _consumed_len {
+    ([], dyn_ind_args = $dyn_ind_args:ident, slice_len = $slice_len:expr $(,)?) => {
+        $crate::pmr::consumed_len(&__COMP_CONSTS.ind_args, $slice_len)
+    };
+    ([$($dyn_count:tt)+], dyn_ind_args = $dyn_ind_args:ident, slice_len = $slice_len:expr $(,)?) => {
+        $crate::pmr::consumed_len(&$dyn_ind_args, $slice_len)
+    };
+}
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_dyn_args {
+    ([] $dyn_args:ident = $value:expr) => {};
+    ([$($dyn_count:tt)+] $dyn_args:ident = $value:expr) => {
+        let $dyn_args = $value;
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _check_dyn_args {
+    (
+        []
+        dyn_args = $dyn_args:ident,
+        dyn_ind_args = $dyn_ind_args:ident,
+        slice = $slice:ident,
+        expected_are_disjoint = $expected_are_disjoint:expr,
+        on_error(|$err:ident| $on_error:expr),
+        then = $then:block
+    ) => {
+        $then
+    };
+    (
+        [$($dyn_count:tt)+]
+        dyn_args = $dyn_args:ident,
+        dyn_ind_args = $dyn_ind_args:ident,
+        slice = $slice:ident,
+        expected_are_disjoint = $expected_are_disjoint:expr,
+        on_error(|$err:ident| $on_error:expr),
+        then = $then:block
+    ) => {{
+        let mut $dyn_ind_args = __COMP_CONSTS.ind_args;
+        match $crate::pmr::compute_mixed_index_arguments(
+            &mut $dyn_ind_args,
+            &[$( ($dyn_count, $dyn_args.$dyn_count.prenorm), )*],
+            $slice.len(),
+            $expected_are_disjoint,
+        ) {
+            $crate::pmr::Ok(()) => $then,
+            $crate::pmr::Err($err) => $on_error,
+        }
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _index_arg_output {
+    (
+        clamped_static,
+        $count:tt,
+        ptr = $ptr:ident,
+        len = $len:ident,
+        lifetime = $lifetime:ident,
+        dyn_args = $dyn_args:ident,
+        dyn_ind_args = $dyn_ind_args:ident,
+        index_method = $index_method:ident,
+    ) => {{
+        use $crate::pmr::{IndexArgument, IndexPointer, Indexer, IndexerParams};
+
+        const __IND_ARG: &IndexArgument = &__COMP_CONSTS.ind_args[$count];
+
+        type __IndexerAlias<T> = Indexer<
+            T,
+            [T; __IND_ARG.len_else_zero()],
+            $crate::pick_type!(
+                $crate::pmr::ClampedIndexKindPicker,
+                IndexArgument::index_kind(__IND_ARG) as usize,
+            ),
+        >;
+
+        let caster: __IndexerAlias<_> = IndexerParams {
+            index: __IND_ARG.start as _,
+            // The IK_RangeClamped Indexer returns a slice up to `slice_len`,
+            // which is the end of the range clamped to the length of the slice.
+            slice_len: $crate::core::cmp::min(__IND_ARG.saturated_end(), $len),
+        }
+        .build();
+
+        IndexPointer::$index_method(caster, $ptr, $lifetime)
+    }};
+    (
+        static,
+        $count:tt,
+        ptr = $ptr:ident,
+        len = $len:ident,
+        lifetime = $lifetime:ident,
+        dyn_args = $dyn_args:ident,
+        dyn_ind_args = $dyn_ind_args:ident,
+        index_method = $index_method:ident,
+    ) => {{
+        use $crate::pmr::{IndexArgument, IndexPointer, Indexer, IndexerParams};
+
+        const __IND_ARG: &IndexArgument = &__COMP_CONSTS.ind_args[$count];
+
+        type __IndexerAlias<T> = Indexer<
+            T,
+            [T; __IND_ARG.len_else_zero()],
+            $crate::index_argument_to_kind_type!(__IND_ARG),
+        >;
+
+        let caster: __IndexerAlias<_> = IndexerParams {
+            index: __IND_ARG.start as _,
+            // The IK_RangeFrom Indexer returns a slice up to `slice_len`,
+            // which is before the start of the arguments relative to the end of the slice.
+            slice_len: IndexArgument::unbounded_end(__COMP_CONSTS.prenorm, $count, $len),
+        }
+        .build();
+
+        IndexPointer::$index_method(caster, $ptr, $lifetime)
+    }};
+    (
+        const,
+        $count:tt,
+        ptr = $ptr:ident,
+        len = $len:ident,
+        lifetime = $lifetime:ident,
+    ) => {{
+        use $crate::pmr::{IndexArgument, Indexer, IndexerParams};
+
+        const __IND_ARG: &IndexArgument = &__COMP_CONSTS.ind_args[$count];
+
+        type __IndexerAlias<T> = Indexer<
+            T,
+            [T; __IND_ARG.len_else_zero()],
+            $crate::index_argument_to_kind_type!(__IND_ARG),
+        >;
+
+        let caster: __IndexerAlias<_> = IndexerParams {
+            index: __IND_ARG.start as _,
+            slice_len: $len,
+        }
+        .build_const();
+
+        caster.index_ptr_const($ptr, $lifetime)
+    }};
+    (
+        (typed $ty:ident),
+        $count:tt,
+        ptr = $ptr:ident,
+        len = $len:ident,
+        lifetime = $lifetime:ident,
+        dyn_args = $dyn_args:ident,
+        dyn_ind_args = $dyn_ind_args:ident,
+        index_method = $index_method:ident,
+    ) => {{
+        use $crate::pmr::{IK_Range, IndexPointer, Indexer, IndexerParams};
+
+        // `IndexArgument::check_type_sizes` ensures that
+        // this argument is a range as long as `$ty`.
+        let caster: Indexer<u8, $crate::endian::$ty, IK_Range> = IndexerParams {
+            index: __COMP_CONSTS.ind_args[$count].start as _,
+            slice_len: $len,
+        }
+        .build();
+
+        $crate::_typed_arg_output!(
+            $index_method,
+            IndexPointer::$index_method(caster, $ptr, $lifetime)
+        )
+    }};
+    (
+        (cast $ty:ty),
+        $count:tt,
+        ptr = $ptr:ident,
+        len = $len:ident,
+        lifetime = $lifetime:ident,
+        dyn_args = $dyn_args:ident,
+        dyn_ind_args = $dyn_ind_args:ident,
+        index_method = $index_method:ident,
+    ) => {{
+        use $crate::pmr::{IK_Range, IndexPointer, Indexer, IndexerParams};
+
+        const __START: usize = __COMP_CONSTS.ind_args[$count].start;
+
+        $crate::pmr::check_cast_alignment::<$ty>($ptr as *const u8, __START, $count);
+
+        // `IndexArgument::check_type_sizes` ensures that
+        // this argument is a range as long as `$ty`.
+        let caster: Indexer<u8, $ty, IK_Range> = IndexerParams {
+            index: __START as _,
+            slice_len: $len,
+        }
+        .build();
+
+        IndexPointer::$index_method(caster, $ptr, $lifetime)
+    }};
+    (
+        (from_end index),
+        $count:tt,
+        ptr = $ptr:ident,
+        len = $len:ident,
+        lifetime = $lifetime:ident,
+        dyn_args = $dyn_args:ident,
+        dyn_ind_args = $dyn_ind_args:ident,
+        index_method = $index_method:ident,
+    ) => {
+        $crate::_index_arg_output! {
+            dyn,
+            $count,
+            ptr = $ptr,
+            len = $len,
+            lifetime = $lifetime,
+            dyn_args = $dyn_args,
+            dyn_ind_args = $dyn_ind_args,
+            index_method = $index_method,
+        }
+    };
+    (
+        (from_end range),
+        $count:tt,
+        ptr = $ptr:ident,
+        len = $len:ident,
+        lifetime = $lifetime:ident,
+        dyn_args = $dyn_args:ident,
+        dyn_ind_args = $dyn_ind_args:ident,
+        index_method = $index_method:ident,
+    ) => {{
+        use $crate::pmr::{IK_Range, IndexPointer, Indexer, IndexerParams};
+
+        // The length of the range is known at compile-time,
+        // its start is only known once the length of the slice is.
+        type __IndexerAlias<T> =
+            Indexer<T, [T; __COMP_CONSTS.prenorm[$count].from_end_len()], IK_Range>;
+
+        let caster: __IndexerAlias<_> = IndexerParams {
+            index: $dyn_ind_args[$count].start as _,
+            slice_len: $len,
+        }
+        .build();
+
+        IndexPointer::$index_method(caster, $ptr, $lifetime)
+    }};
+    (
+        dyn,
+        $count:tt,
+        ptr = $ptr:ident,
+        len = $len:ident,
+        lifetime = $lifetime:ident,
+        dyn_args = $dyn_args:ident,
+        dyn_ind_args = $dyn_ind_args:ident,
+        index_method = $index_method:ident,
+    ) => {
+        $crate::pmr::IndexPointer::$index_method(
+            $dyn_args.$count.indexer(&$dyn_ind_args[$count], $len),
+            $ptr,
+            $lifetime,
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _typed_arg_output {
+    (index_ptr, $reference:expr) => {
+        $reference.get()
+    };
+    (index_ptr_mut, $reference:expr) => {
+        $reference
+    };
 }
 
 /// For immutable indexing of slices with multiple indices/ranges.
@@ -191,10 +867,10 @@ macro_rules! _index_impl {
 ///
 #[macro_export]
 macro_rules! multindex {
-    ( $slice:expr; $($index:expr),* $(,)? ) => (
+    ( $slice:expr; $($args:tt)* ) => (
         $crate::_index_impl!{
             slice = $slice;
-            indices[$($index,)*];
+            args[$($args)*];
             (
                 expected_are_disjoint = $crate::pmr::AreAllDisjoint::NO,
                 on_out_of_bounds = panic,
@@ -233,14 +909,21 @@ macro_rules! multindex {
 ///
 /// // The `..` here returns a `&mut [u8]`, since it's a trailing unbounded range
 /// assert_eq!(multindex_mut!(arr; ..2, ..), (&mut [3, 5], &mut [8, 13, 21, 34][..]));
+///
+/// // `dyn` arguments are checked at runtime, returning a `&mut [u8]` for ranges.
+/// let (offset, len) = (3, 2);
+/// assert_eq!(
+///     multindex_mut!(arr; 0..2, 2, dyn offset..offset + len),
+///     (&mut [3, 5], &mut 8, &mut [13, 21][..]),
+/// );
 /// ```
 ///
 #[macro_export]
 macro_rules! multindex_mut {
-    ( $slice:expr; $($index:expr),* $(,)? ) => (
+    ( $slice:expr; $($args:tt)* ) => (
         $crate::_index_impl!{
             slice = $slice;
-            indices[$($index,)*];
+            args[$($args)*];
             (
                 expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
                 on_out_of_bounds = panic,
@@ -286,10 +969,10 @@ macro_rules! multindex_mut {
 ///
 #[macro_export]
 macro_rules! multiget {
-    ( $slice:expr; $($index:expr),* $(,)? ) => (
+    ( $slice:expr; $($args:tt)* ) => (
         $crate::_index_impl!{
             slice = $slice;
-            indices[$($index,)*];
+            args[$($args)*];
             (
                 expected_are_disjoint = $crate::pmr::AreAllDisjoint::NO,
                 on_out_of_bounds = option,
@@ -331,14 +1014,18 @@ macro_rules! multiget {
 /// assert_eq!(multiget_mut!(arr; 0, 1, 10), None);
 ///
 /// assert_eq!(multiget_mut!(arr; 0..10), None);
+///
+/// let index = 1;
+/// // Returns `None` because the `dyn` argument overlaps with the `0..2` argument.
+/// assert_eq!(multiget_mut!(arr; 0..2, dyn index), None);
 /// ```
 ///
 #[macro_export]
 macro_rules! multiget_mut {
-    ( $slice:expr; $($index:expr),* $(,)? ) => (
+    ( $slice:expr; $($args:tt)* ) => (
         $crate::_index_impl!{
             slice = $slice;
-            indices[$($index,)*];
+            args[$($args)*];
             (
                 expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
                 on_out_of_bounds = option,
@@ -349,3 +1036,431 @@ macro_rules! multiget_mut {
         }
     );
 }
+
+/// For immutable indexing of slices with multiple indices/ranges,
+/// bounds checking each argument separately.
+///
+/// This macro returns a tuple with an `Option` for each argument,
+/// which is `Some` if that argument is in bounds of the passed slice,
+/// and `None` if it isn't.
+/// This is unlike [`multiget`], which returns `None` if any argument is out of bounds.
+///
+/// This macro doesn't take runtime (`dyn`) arguments.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multiget_each;
+///
+/// // A record with an optional 2 byte trailer at the end.
+/// let short = [3u8, 5, 8, 13];
+/// let long = [3u8, 5, 8, 13, 21, 34];
+///
+/// assert_eq!(multiget_each!(short; 0, 1..4, 4..6), (Some(&3), Some(&[5, 8, 13]), None));
+///
+/// assert_eq!(
+///     multiget_each!(long; 0, 1..4, 4..6),
+///     (Some(&3), Some(&[5, 8, 13]), Some(&[21, 34])),
+/// );
+///
+/// // A trailing `..` is in bounds as long as it starts at or before the end of the slice.
+/// assert_eq!(multiget_each!(short; ..2, 6..), (Some(&[3, 5]), None));
+/// assert_eq!(multiget_each!(short; ..2, ..), (Some(&[3, 5]), Some(&[8, 13][..])));
+///
+/// ```
+///
+/// [`multiget`]: ./macro.multiget.html
+#[macro_export]
+macro_rules! multiget_each {
+    ( $slice:expr; $($args:tt)* ) => (
+        $crate::_index_impl!{
+            slice = $slice;
+            args[$($args)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::NO,
+                on_out_of_bounds = each,
+                auto_borrow_method = _11748397628858797803_borrow_self,
+                slice_parts = SliceParts,
+                index_method = index_ptr,
+                no_runtime = yes,
+            )
+        }
+    );
+}
+
+/// For mutable indexing of slices with multiple indices/ranges,
+/// bounds checking each argument separately.
+///
+/// This macro returns a tuple with an `Option` for each argument,
+/// which is `Some` if that argument is in bounds of the passed slice,
+/// and `None` if it isn't.
+/// This is unlike [`multiget_mut`], which returns `None` if any argument is out of bounds.
+///
+/// The arguments are checked not to overlap at compile-time,
+/// regardless of the length of the slice.
+///
+/// This macro doesn't take runtime (`dyn`) arguments.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multiget_each_mut;
+///
+/// fn bump_fields(record: &mut [u8]) {
+///     let (kind, len, trailer) = multiget_each_mut!(record; 0, 1, 2..4);
+///     *kind.unwrap() += 1;
+///     *len.unwrap() += 1;
+///     if let Some(trailer) = trailer {
+///         *trailer = [0xFF; 2];
+///     }
+/// }
+///
+/// let mut short = [3u8, 5, 8];
+/// bump_fields(&mut short);
+/// assert_eq!(short, [4, 6, 8]);
+///
+/// let mut long = [3u8, 5, 8, 13];
+/// bump_fields(&mut long);
+/// assert_eq!(long, [4, 6, 0xFF, 0xFF]);
+///
+/// ```
+///
+/// [`multiget_mut`]: ./macro.multiget_mut.html
+#[macro_export]
+macro_rules! multiget_each_mut {
+    ( $slice:expr; $($args:tt)* ) => (
+        $crate::_index_impl!{
+            slice = $slice;
+            args[$($args)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                on_out_of_bounds = each,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                slice_parts = SlicePartsMut,
+                index_method = index_ptr_mut,
+                no_runtime = yes,
+            )
+        }
+    );
+}
+
+/// For immutable indexing of slices with multiple indices/ranges,
+/// truncating the ranges that extend past the end of the slice.
+///
+/// This macro returns `None` if any index or typed argument (eg: `u32be @ 0..4`)
+/// is out of bounds for the passed slice, and `Some` otherwise.
+///
+/// The range arguments return slices instead of arrays,
+/// containing the elements of the range that are in bounds of the passed slice,
+/// which is empty if the range starts at or after the end of the slice.
+///
+/// This macro doesn't take runtime (`dyn`) arguments.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multiget_clamped;
+///
+/// // A partially filled buffer of a stream of bytes.
+/// let buffer = [3u8, 5, 8, 13, 21];
+///
+/// assert_eq!(
+///     multiget_clamped!(buffer; 0, 1..4, 4..8, 8..),
+///     Some((&3, &[5, 8, 13][..], &[21][..], &[][..])),
+/// );
+///
+/// assert_eq!(
+///     multiget_clamped!(buffer[..2]; 0, 1..4, 4..8, 8..),
+///     Some((&3, &[5][..], &[][..], &[][..])),
+/// );
+///
+/// // Indices still need to be in bounds
+/// assert_eq!(multiget_clamped!(buffer; 0, 1..4, 5), None);
+///
+/// ```
+///
+#[macro_export]
+macro_rules! multiget_clamped {
+    ( $slice:expr; $($args:tt)* ) => (
+        $crate::_index_impl!{
+            slice = $slice;
+            args[$($args)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::NO,
+                on_out_of_bounds = clamped,
+                auto_borrow_method = _11748397628858797803_borrow_self,
+                slice_parts = SliceParts,
+                index_method = index_ptr,
+                no_runtime = yes,
+                clamped = yes,
+            )
+        }
+    );
+}
+
+/// For mutable indexing of slices with multiple indices/ranges,
+/// truncating the ranges that extend past the end of the slice.
+///
+/// This macro returns `None` if any index or typed argument (eg: `u32be @ 0..4`)
+/// is out of bounds for the passed slice, and `Some` otherwise.
+///
+/// The range arguments return slices instead of arrays,
+/// containing the elements of the range that are in bounds of the passed slice,
+/// which is empty if the range starts at or after the end of the slice.
+///
+/// The arguments are checked not to overlap at compile-time,
+/// regardless of the length of the slice.
+///
+/// This macro doesn't take runtime (`dyn`) arguments.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multiget_clamped_mut;
+///
+/// let mut buffer = [3u8, 5, 8, 13, 21];
+///
+/// {
+///     let (header, body) = multiget_clamped_mut!(buffer[..3]; 0, 1..5).unwrap();
+///     *header = 0;
+///     body.iter_mut().for_each(|x| *x = 1);
+/// }
+/// assert_eq!(buffer, [0, 1, 1, 13, 21]);
+///
+/// assert_eq!(multiget_clamped_mut!(buffer[..0]; 0, 1..5), None);
+///
+/// ```
+///
+#[macro_export]
+macro_rules! multiget_clamped_mut {
+    ( $slice:expr; $($args:tt)* ) => (
+        $crate::_index_impl!{
+            slice = $slice;
+            args[$($args)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                on_out_of_bounds = clamped,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                slice_parts = SlicePartsMut,
+                index_method = index_ptr_mut,
+                no_runtime = yes,
+                clamped = yes,
+            )
+        }
+    );
+}
+
+/// For fallible immutable indexing of slices with multiple indices/ranges.
+///
+/// This macro returns an `Err(OutOfBounds)` if the indices/ranges are
+/// out of bounds for the passed slice,
+/// returns `Ok` if they are in bounds.
+///
+/// The [`OutOfBounds`] error has the length of the slice,
+/// the length that the slice needed to be,
+/// and which argument required that length.
+///
+/// This macro doesn't take `dyn` arguments, since they can fail for reasons
+/// that an `OutOfBounds` error can't describe.
+/// Arguments relative to the end of the slice (eg: `end - 4..`) are allowed,
+/// if they start before a range with an unbounded end right before them
+/// (eg: `4.., end - 2`), the error has the length that the slice needs for them not to.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::{try_multindex, OutOfBounds};
+///
+/// let arr = [3u8, 5, 8, 13, 21, 34];
+///
+/// assert_eq!(try_multindex!(arr; 0, 2..=4), Ok((&3, &[8, 13, 21])));
+///
+/// assert_eq!(
+///     try_multindex!(arr; 0, 1, 10),
+///     Err(OutOfBounds{slice_len: 6, max_bounded_end: 11, argument: 2}),
+/// );
+///
+/// assert_eq!(
+///     try_multindex!(arr; 0..10, 4),
+///     Err(OutOfBounds{slice_len: 6, max_bounded_end: 10, argument: 0}),
+/// );
+/// ```
+///
+/// [`OutOfBounds`]: ./struct.OutOfBounds.html
+#[macro_export]
+macro_rules! try_multindex {
+    ( $slice:expr; $($args:tt)* ) => (
+        $crate::_index_impl!{
+            slice = $slice;
+            args[$($args)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::NO,
+                on_out_of_bounds = result,
+                auto_borrow_method = _11748397628858797803_borrow_self,
+                slice_parts = SliceParts,
+                index_method = index_ptr,
+                no_dyn = yes,
+            )
+        }
+    );
+}
+
+/// For fallible mutable indexing of slices with multiple indices/ranges.
+///
+/// This macro returns an `Err(OutOfBounds)` if the indices/ranges are
+/// out of bounds for the passed slice,
+/// returns `Ok` if they are in bounds.
+///
+/// The [`OutOfBounds`] error has the length of the slice,
+/// the length that the slice needed to be,
+/// and which argument required that length.
+///
+/// This macro doesn't take `dyn` arguments, since they can fail for reasons
+/// that an `OutOfBounds` error can't describe.
+/// Arguments relative to the end of the slice (eg: `end - 4..`) are allowed,
+/// if they overlap the arguments relative to the start of the slice,
+/// the error has the length that the slice needs for them not to.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::{try_multindex_mut, OutOfBounds};
+///
+/// let mut arr = [0u8, 0, 0, 10, 20];
+///
+/// assert_eq!(parse_u32(&mut arr), Ok((10, &mut [20][..])));
+///
+/// assert_eq!(
+///     parse_u32(&mut arr[2..]),
+///     Err(OutOfBounds{slice_len: 3, max_bounded_end: 4, argument: 0}),
+/// );
+///
+/// fn parse_u32(bytes: &mut [u8]) -> Result<(u32, &mut [u8]), OutOfBounds> {
+///     let (int, rem) = try_multindex_mut!(bytes; ..4, ..)?;
+///     Ok((u32::from_be_bytes(*int), rem))
+/// }
+/// ```
+///
+/// [`OutOfBounds`]: ./struct.OutOfBounds.html
+#[macro_export]
+macro_rules! try_multindex_mut {
+    ( $slice:expr; $($args:tt)* ) => (
+        $crate::_index_impl!{
+            slice = $slice;
+            args[$($args)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                on_out_of_bounds = result,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                slice_parts = SlicePartsMut,
+                index_method = index_ptr_mut,
+                no_dyn = yes,
+            )
+        }
+    );
+}
+
+/// For mutable indexing of slices with multiple indices/ranges,
+/// that must cover every element up to the end of the last argument.
+///
+/// This is like [`multindex_mut`], except that the arguments are also checked
+/// at compile-time to start at 0, and for each one to start where the previous one ends,
+/// so that no elements are left out between them.
+/// Because of that, the arguments must be passed in ascending order,
+/// and `dyn` arguments aren't allowed.
+///
+/// To also cover the end of the slice, the last argument can be an unbounded range
+/// (eg: `4..`).
+///
+/// For arrays, the [`multindex_arr_exhaustive_mut`] macro also checks that
+/// the arguments reach the end of the array.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Panics
+///
+/// This macro panics at runtime if the indices/ranges are
+/// out of bounds for the passed slice.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::{endian::u16be, multindex_exhaustive_mut};
+///
+/// let mut packet = *b"\x01\x00\x03abc";
+///
+/// let (version, len, payload) = multindex_exhaustive_mut!(packet; 0, u16be @ 1..3, ..);
+/// *version += 1;
+/// len.set(len.get() + 1);
+/// payload.make_ascii_uppercase();
+///
+/// assert_eq!(&packet, b"\x02\x00\x04ABC");
+/// ```
+///
+/// This doesn't compile, because there's a gap between the first and second arguments.
+///
+/// ```compile_fail
+/// let mut packet = *b"\x01\x00\x03abc";
+///
+/// let _ = multindex::multindex_exhaustive_mut!(packet; 0, 2..4, ..);
+/// ```
+///
+/// [`multindex_mut`]: ./macro.multindex_mut.html
+/// [`multindex_arr_exhaustive_mut`]: ./macro.multindex_arr_exhaustive_mut.html
+#[macro_export]
+macro_rules! multindex_exhaustive_mut {
+    ( $slice:expr; $($args:tt)* ) => (
+        $crate::_index_impl!{
+            slice = $slice;
+            args[$($args)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                on_out_of_bounds = panic,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                slice_parts = SlicePartsMut,
+                index_method = index_ptr_mut,
+                exhaustive = yes,
+            )
+        }
+    );
+}
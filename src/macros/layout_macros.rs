@@ -0,0 +1,374 @@
+/// Declares the layout of named fields in a byte slice,
+/// with structs of references to those fields.
+///
+/// The fields are declared with the same arguments that [`multindex_mut`] takes,
+/// and are checked not to overlap at compile-time.
+///
+/// # Generated items
+///
+/// For `layout!{ pub struct Header { ... } }`,
+/// this macro generates:
+///
+/// - A `Header` unit struct, with:
+///     - A `MIN_LEN` associated constant,
+///       the minimum length of a slice that the fields are in bounds of.
+///     - A `view(&[u8]) -> Option<HeaderRef<'_>>` associated function.
+///     - A `view_mut(&mut [u8]) -> Option<HeaderMut<'_>>` associated function.
+///
+/// - A `HeaderRef<'a>` struct, with a public `&'a _` field for each field in the layout.
+///
+/// - A `HeaderMut<'a>` struct, with a public `&'a mut _` field for each field in the layout.
+///
+/// The view structs can be given other names by writing them after the name of the layout:
+///
+/// ```rust
+/// multindex::layout! {
+///     pub struct Header -> HeaderView, HeaderViewMut {
+///         magic: 0..4,
+///         len: 4..6,
+///     }
+/// }
+///
+/// let header: HeaderView<'_> = Header::view(b"MAGI\x00\x03").unwrap();
+/// assert_eq!(header.len, &[0, 3]);
+/// ```
+///
+/// The fields have the types that [`multindex_mut`] returns for the same arguments:
+/// integer arguments are `u8`, bounded ranges are `[u8; LEN]` arrays,
+/// and a trailing unbounded range is a `[u8]` slice.
+///
+/// # Exhaustive layouts
+///
+/// Writing `exhaustive` before `struct`
+/// (eg: `layout!{ pub exhaustive struct Header { ... } }`)
+/// also checks at compile-time that the fields start at 0,
+/// and that each field starts where the previous one ends,
+/// like the arguments of [`multindex_exhaustive_mut`].
+/// This ensures that every byte up to `MIN_LEN` is in some field.
+///
+/// ```rust
+/// multindex::layout! {
+///     pub exhaustive struct Header {
+///         magic: 0..4,
+///         version: 4,
+///         flags: 5,
+///         len: 6..8,
+///         payload: 8..,
+///     }
+/// }
+///
+/// let header = Header::view(b"MAGI\x02\x01\x00\x03abc").unwrap();
+/// assert_eq!(*header.flags, 1);
+/// assert_eq!(header.payload, b"abc");
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// multindex::layout! {
+///     /// The header of a packet.
+///     pub struct Header {
+///         magic: 0..4,
+///         version: 4,
+///         len: 6..8,
+///         payload: 8..,
+///     }
+/// }
+///
+/// assert_eq!(Header::MIN_LEN, 8);
+///
+/// let mut packet = *b"MAGI\x02\x00\x00\x03abc";
+///
+/// let header: HeaderRef<'_> = Header::view(&packet).unwrap();
+/// assert_eq!(header.magic, b"MAGI");
+/// assert_eq!(*header.version, 2);
+/// assert_eq!(u16::from_be_bytes(*header.len), 3);
+/// assert_eq!(header.payload, b"abc");
+///
+/// let header: HeaderMut<'_> = Header::view_mut(&mut packet).unwrap();
+/// *header.version += 1;
+/// header.payload.make_ascii_uppercase();
+/// assert_eq!(&packet, b"MAGI\x03\x00\x00\x03ABC");
+///
+/// assert!(Header::view(&packet[..7]).is_none());
+///
+/// ```
+///
+/// This doesn't compile, because the `version` field overlaps with the `magic` field.
+///
+/// ```compile_fail
+/// multindex::layout! {
+///     pub struct Header {
+///         magic: 0..4,
+///         version: 3,
+///     }
+/// }
+/// ```
+///
+/// This doesn't compile, because the byte at index 5 isn't in any field.
+///
+/// ```compile_fail
+/// multindex::layout! {
+///     pub exhaustive struct Header {
+///         magic: 0..4,
+///         version: 4,
+///         len: 6..8,
+///     }
+/// }
+/// ```
+///
+/// [`multindex_mut`]: ./macro.multindex_mut.html
+/// [`multindex_exhaustive_mut`]: ./macro.multindex_exhaustive_mut.html
+#[macro_export]
+macro_rules! layout {
+    (
+        $(#[$attr:meta])*
+        $vis:vis exhaustive struct $name:ident {
+            $($fields:tt)*
+        }
+    ) => {
+        $crate::pmr::paste!{
+            $crate::layout!{
+                $(#[$attr])*
+                $vis exhaustive struct $name -> [<$name Ref>], [<$name Mut>] {
+                    $($fields)*
+                }
+            }
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($fields:tt)*
+        }
+    ) => {
+        $crate::pmr::paste!{
+            $crate::layout!{
+                $(#[$attr])*
+                $vis struct $name -> [<$name Ref>], [<$name Mut>] {
+                    $($fields)*
+                }
+            }
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis exhaustive struct $name:ident -> $ref_name:ident, $mut_name:ident {
+            $( $(#[$field_attr:meta])* $field:ident : $index:expr ),* $(,)?
+        }
+    ) => {
+        $crate::_layout_impl!{
+            @accum
+            [$(#[$attr])*] $vis $name $ref_name $mut_name [exhaustive = yes,]
+            []
+            [$( ($(#[$field_attr])*) $field $index, )*]
+            [
+                0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+                16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+                32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+                48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+                64
+            ]
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident -> $ref_name:ident, $mut_name:ident {
+            $( $(#[$field_attr:meta])* $field:ident : $index:expr ),* $(,)?
+        }
+    ) => {
+        $crate::_layout_impl!{
+            @accum
+            [$(#[$attr])*] $vis $name $ref_name $mut_name []
+            []
+            [$( ($(#[$field_attr])*) $field $index, )*]
+            [
+                0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+                16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+                32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+                48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+                64
+            ]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _layout_impl {
+    (@accum
+        $attrs:tt $vis:vis $name:ident $ref_name:ident $mut_name:ident $exhaustive:tt
+        [$($prev:tt)*]
+        [$field_attrs:tt $field:ident $index:expr, $($rem_fields:tt)*]
+        [$count:tt $($rem_count:tt)*]
+    ) => {
+        $crate::_layout_impl!{
+            @accum
+            $attrs $vis $name $ref_name $mut_name $exhaustive
+            [$($prev)* ($count $field_attrs $field $index)]
+            [$($rem_fields)*]
+            [$($rem_count)*]
+        }
+    };
+    (@accum
+        [$($attr:tt)*] $vis:vis $name:ident $ref_name:ident $mut_name:ident [$($exhaustive:tt)*]
+        [$(($count:tt ($($field_attr:tt)*) $field:ident $index:expr))*]
+        []
+        $rem_count:tt
+    ) => {
+        $($attr)*
+        $vis struct $name;
+
+        #[allow(dead_code)]
+        impl $name {
+            const __LAYOUT: &'static $crate::pmr::ComputedConstants<[$crate::pmr::IndexArgument]> =
+                &$crate::new_IndexArgumentsAndStats!(@from_index_macro; $($index),*);
+
+            /// The minimum length of a slice that the fields are in bounds of.
+            pub const MIN_LEN: usize = Self::__LAYOUT.stats.max_bounded_end;
+
+            /// Gets references to the fields in `slice`,
+            /// returning `None` if `slice` is shorter than `MIN_LEN`.
+            pub fn view(slice: &[u8]) -> $crate::pmr::Option<$ref_name<'_>> {
+                let ($($field,)*) = $crate::_index_impl!{
+                    slice = slice;
+                    args[$($index),*];
+                    (
+                        expected_are_disjoint = $crate::pmr::AreAllDisjoint::NO,
+                        on_out_of_bounds = option,
+                        auto_borrow_method = _11748397628858797803_borrow_self,
+                        slice_parts = SliceParts,
+                        index_method = index_ptr,
+                        $($exhaustive)*
+                    )
+                }?;
+                $crate::pmr::Some($ref_name { $($field,)* })
+            }
+
+            /// Gets mutable references to the fields in `slice`,
+            /// returning `None` if `slice` is shorter than `MIN_LEN`.
+            pub fn view_mut(slice: &mut [u8]) -> $crate::pmr::Option<$mut_name<'_>> {
+                let ($($field,)*) = $crate::_index_impl!{
+                    slice = slice;
+                    args[$($index),*];
+                    (
+                        expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                        on_out_of_bounds = option,
+                        auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                        slice_parts = SlicePartsMut,
+                        index_method = index_ptr_mut,
+                        $($exhaustive)*
+                    )
+                }?;
+                $crate::pmr::Some($mut_name { $($field,)* })
+            }
+        }
+
+        /// References to the fields of a byte layout, returned by its `view` function.
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        $vis struct $ref_name<'a> {
+            $(
+                $($field_attr)*
+                pub $field: &'a $crate::_layout_field_type!($name, $count),
+            )*
+        }
+
+        /// Mutable references to the fields of a byte layout,
+        /// returned by its `view_mut` function.
+        #[derive(Debug, PartialEq, Eq)]
+        $vis struct $mut_name<'a> {
+            $(
+                $($field_attr)*
+                pub $field: &'a mut $crate::_layout_field_type!($name, $count),
+            )*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _layout_field_type {
+    ($name:ident, $count:tt) => {
+        <$crate::pmr::Indexer<
+            u8,
+            [u8; $name::__LAYOUT.ind_args[$count].len_else_zero()],
+            $crate::index_argument_to_kind_type!(&$name::__LAYOUT.ind_args[$count]),
+        > as $crate::pmr::IndexPointer>::Output
+    };
+}
+
+/// Declares the layout of a `#[repr(C)]` struct's fields in a byte slice,
+/// with structs of references to those fields.
+///
+/// This is like [`layout`], except that the span of each field is computed from
+/// its offset and size in the struct, instead of being passed as a range.
+/// Each field is a `[u8; SIZE]` array in the generated view structs.
+///
+/// Only the listed fields are in the layout,
+/// and `MIN_LEN` is the end of the last one of them
+/// (which can be smaller than the size of the struct, if it has trailing padding).
+///
+/// The struct should be `#[repr(C)]` (or another representation with a defined layout),
+/// since the layout of a `#[repr(Rust)]` struct can change between compilations.
+///
+/// This requires the "rust_1_77" feature.
+///
+/// # Example
+///
+/// ```rust
+/// #[repr(C)]
+/// pub struct Header {
+///     magic: [u8; 4],
+///     version: u8,
+///     flags: u8,
+///     len: u16,
+/// }
+///
+/// multindex::layout_of! {
+///     pub struct HeaderLayout -> HeaderRef, HeaderMut for Header {
+///         magic,
+///         version,
+///         len,
+///     }
+/// }
+///
+/// assert_eq!(HeaderLayout::MIN_LEN, core::mem::size_of::<Header>());
+///
+/// let mut packet = *b"MAGI\x02\x00\x03\x00";
+///
+/// let header: HeaderMut<'_> = HeaderLayout::view_mut(&mut packet).unwrap();
+/// assert_eq!(header.magic, b"MAGI");
+/// assert_eq!(header.version, &mut [2]);
+/// assert_eq!(u16::from_le_bytes(*header.len), 3);
+/// *header.len = 8u16.to_le_bytes();
+///
+/// assert_eq!(&packet, b"MAGI\x02\x00\x08\x00");
+///
+/// assert!(HeaderLayout::view(&packet[..7]).is_none());
+///
+/// ```
+///
+/// [`layout`]: ./macro.layout.html
+#[cfg(feature = "rust_1_77")]
+#[macro_export]
+macro_rules! layout_of {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident -> $ref_name:ident, $mut_name:ident for $struct:ty {
+            $( $(#[$field_attr:meta])* $field:ident ),* $(,)?
+        }
+    ) => {
+        $crate::layout!{
+            $(#[$attr])*
+            $vis struct $name -> $ref_name, $mut_name {
+                $(
+                    $(#[$field_attr])*
+                    $field: {
+                        let start = $crate::core::mem::offset_of!($struct, $field);
+                        start..start + $crate::pmr::field_size(|this: &$struct| &this.$field)
+                    },
+                )*
+            }
+        }
+    };
+}
@@ -0,0 +1,294 @@
+/// For indexing slices with multiple indices/ranges,
+/// some mutably (`mut`) and some immutably (`ref`).
+///
+/// Each argument must be prefixed with `mut` or `ref`,
+/// `mut` arguments return mutable references, and `ref` arguments return shared references
+/// (or the decoded value, for typed arguments like `ref u32be @ 0..4`).
+///
+/// `mut` arguments are checked not to overlap with any other argument,
+/// like all the arguments of [`multindex_mut`],
+/// while `ref` arguments can overlap each other freely,
+/// like the arguments of [`multindex`].
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Panics
+///
+/// This macro panics at runtime if the indices/ranges are
+/// out of bounds for the passed slice,
+/// or if a `dyn` argument overlaps with another argument that it must not overlap.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multindex_mixed;
+///
+/// let mut arr = [3u8, 5, 8, 13, 21, 34, 55, 89];
+///
+/// // The `ref` arguments overlap each other, but not the `mut` arguments.
+/// let (sum, first, window, last) = multindex_mixed!(arr; mut 0..2, ref 2, ref 2..5, mut 7);
+///
+/// *sum = [first + window[1], window.iter().sum()];
+/// *last = *first;
+///
+/// assert_eq!(arr, [21, 42, 8, 13, 21, 34, 55, 8]);
+/// ```
+///
+/// This doesn't compile, because the `mut 0..4` argument overlaps with the `ref 2` argument.
+///
+/// ```compile_fail
+/// let mut arr = [3u8, 5, 8, 13, 21, 34, 55, 89];
+///
+/// let _ = multindex::multindex_mixed!(arr; mut 0..4, ref 2, ref 3..6, mut 7);
+/// ```
+///
+/// [`multindex`]: ./macro.multindex.html
+/// [`multindex_mut`]: ./macro.multindex_mut.html
+#[macro_export]
+macro_rules! multindex_mixed {
+    ( $slice:expr; $($args:tt)* ) => (
+        $crate::_mixed_index_impl!{
+            @accum
+            $slice;
+            []
+            []
+            [$($args)*]
+            [
+                0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+                16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+                32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+                48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+                64
+            ]
+        }
+    );
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _mixed_index_impl {
+    (@accum
+        $slice:expr;
+        [$($prev:tt)*]
+        [$($dyn_count:tt)*]
+        [ $mutability:ident dyn $index:expr $(, $($rem_args:tt)*)?]
+        [ $count:tt $($rem_count:tt)*]
+    )=>{
+        $crate::_mixed_index_impl!{
+            @accum
+            $slice;
+            [$($prev)* ($count, $mutability, dyn, $index) ]
+            [$($dyn_count)* $count]
+            [$($($rem_args)*)?]
+            [$($rem_count)*]
+        }
+    };
+    (@accum
+        $slice:expr;
+        [$($prev:tt)*]
+        $dyn_counts:tt
+        [ $mutability:ident $ty:ident @ $index:expr $(, $($rem_args:tt)*)?]
+        [ $count:tt $($rem_count:tt)*]
+    )=>{
+        $crate::_mixed_index_impl!{
+            @accum
+            $slice;
+            [$($prev)* ($count, $mutability, (typed $ty), $index) ]
+            $dyn_counts
+            [$($($rem_args)*)?]
+            [$($rem_count)*]
+        }
+    };
+    (@accum
+        $slice:expr;
+        [$($prev:tt)*]
+        $dyn_counts:tt
+        [ $mutability:ident cast < $ty:ty > @ $index:expr $(, $($rem_args:tt)*)?]
+        [ $count:tt $($rem_count:tt)*]
+    )=>{
+        $crate::_mixed_index_impl!{
+            @accum
+            $slice;
+            [$($prev)* ($count, $mutability, (cast $ty), $index) ]
+            $dyn_counts
+            [$($($rem_args)*)?]
+            [$($rem_count)*]
+        }
+    };
+    (@accum
+        $slice:expr;
+        [$($prev:tt)*]
+        $dyn_counts:tt
+        [ $mutability:ident $index:expr $(, $($rem_args:tt)*)?]
+        [ $count:tt $($rem_count:tt)*]
+    )=>{
+        $crate::_mixed_index_impl!{
+            @accum
+            $slice;
+            [$($prev)* ($count, $mutability, static, $index) ]
+            $dyn_counts
+            [$($($rem_args)*)?]
+            [$($rem_count)*]
+        }
+    };
+    (@accum
+        $slice:expr;
+        []
+        []
+        []
+        $counts:tt
+    )=>({
+        let _ = $slice;
+        ()
+    });
+    (@accum
+        $slice:expr;
+        [$(($count:tt, $mutability:ident, $kind:tt, $index:expr))*]
+        [$($dyn_count:tt)*]
+        []
+        [$index_arg_count:tt $($rem_count:tt)*]
+    )=>({
+        // Only the `mut` arguments are checked not to overlap with the other arguments.
+        const __EXPECTED_ARE_DISJOINT: $crate::pmr::AreAllDisjoint =
+            $crate::pmr::AreAllDisjoint::OnlyMut(&[$($crate::_mixed_is_mut!($mutability),)*]);
+
+        $crate::_declare_comp_consts!{
+            args = [$(($kind, $index))*],
+            index_arg_count = $index_arg_count,
+            expected_are_disjoint = __EXPECTED_ARE_DISJOINT,
+        }
+
+        // The runtime arguments are evaluated before `$slice` is borrowed,
+        // so that they can be computed from the slice.
+        $crate::_declare_dyn_args!{
+            [$($dyn_count)*]
+            dyn_args = ($( $crate::_index_arg_dyn_value!($kind, $index), )*)
+        }
+
+        use $crate::utils::BorrowSelf as _;
+
+        match $slice._11748397628858797803_borrow_self_mut() { slice => unsafe {
+            use $crate::pmr::SlicePartsMut;
+
+            if __COMP_CONSTS.stats.max_bounded_end > slice.len() {
+                $crate::pmr::panic_on_oob_max_index(__COMP_CONSTS.stats.max_bounded_end, slice.len())
+            }
+
+            $crate::_check_dyn_args!{
+                [$($dyn_count)*]
+                dyn_args = dyn_args,
+                dyn_ind_args = dyn_ind_args,
+                slice = slice,
+                expected_are_disjoint = __EXPECTED_ARE_DISJOINT,
+                on_error(|e| $crate::pmr::panic_on_dyn_error(e)),
+                then = {
+                    // `lifetime` is a `PhantomData<&'a mut T>` used to ensure that the
+                    // references returned by `IndexPointer::index_ptr_*` have the correct
+                    // lifetime, `shared_lifetime` is its equivalent for shared references.
+                    let SlicePartsMut{ptr, len, lifetime} = SlicePartsMut::new({slice});
+                    let shared_lifetime = $crate::pmr::shared_lifetime(lifetime);
+
+                    ($(
+                        $crate::_mixed_arg_output!{
+                            $mutability,
+                            $kind,
+                            $count,
+                            ptr = ptr,
+                            len = len,
+                            lifetime = lifetime,
+                            shared_lifetime = shared_lifetime,
+                            dyn_args = dyn_args,
+                            dyn_ind_args = dyn_ind_args,
+                        },
+                    )*)
+                }
+            }
+        }}
+    });
+    (@accum
+        $slice:expr;
+        $prev:tt
+        $dyn_counts:tt
+        [$($rem_args:tt)+]
+        $counts:tt
+    )=>{
+        $crate::pmr::compile_error!{
+            "expected `mut` or `ref` before each argument of `multindex_mixed`"
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _mixed_is_mut {
+    (mut) => {
+        true
+    };
+    (ref) => {
+        false
+    };
+    ($other:ident) => {
+        $crate::pmr::compile_error! {$crate::pmr::concat!(
+            "expected `mut` or `ref` before each argument of `multindex_mixed`, found `",
+            $crate::pmr::stringify!($other),
+            "`",
+        )}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _mixed_arg_output {
+    (
+        mut,
+        $kind:tt,
+        $count:tt,
+        ptr = $ptr:ident,
+        len = $len:ident,
+        lifetime = $lifetime:ident,
+        shared_lifetime = $shared_lifetime:ident,
+        dyn_args = $dyn_args:ident,
+        dyn_ind_args = $dyn_ind_args:ident,
+    ) => {
+        $crate::_index_arg_output! {
+            $kind,
+            $count,
+            ptr = $ptr,
+            len = $len,
+            lifetime = $lifetime,
+            dyn_args = $dyn_args,
+            dyn_ind_args = $dyn_ind_args,
+            index_method = index_ptr_mut,
+        }
+    };
+    (
+        ref,
+        $kind:tt,
+        $count:tt,
+        ptr = $ptr:ident,
+        len = $len:ident,
+        lifetime = $lifetime:ident,
+        shared_lifetime = $shared_lifetime:ident,
+        dyn_args = $dyn_args:ident,
+        dyn_ind_args = $dyn_ind_args:ident,
+    ) => {
+        $crate::_index_arg_output! {
+            $kind,
+            $count,
+            ptr = $ptr,
+            len = $len,
+            lifetime = $shared_lifetime,
+            dyn_args = $dyn_args,
+            dyn_ind_args = $dyn_ind_args,
+            index_method = index_ptr,
+        }
+    };
+    // `_mixed_is_mut` already errors for anything other than `mut` and `ref`
+    ($other:ident, $($rest:tt)*) => {
+        ()
+    };
+}
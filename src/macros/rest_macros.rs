@@ -0,0 +1,100 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _uncovered_slices {
+    (
+        $rest:tt,
+        ptr = $ptr:ident,
+        len = $len:ident,
+        lifetime = $lifetime:ident $(,)?
+    ) => {{
+        use $crate::pmr::IndexArgument;
+
+        const __REGION_COUNT: usize = IndexArgument::count_uncovered(&__COMP_CONSTS.ind_args);
+
+        const __REGIONS: [IndexArgument; __REGION_COUNT] =
+            $crate::pmr::uncovered_regions(&__COMP_CONSTS.ind_args);
+
+        $crate::pmr::uncovered_slices_mut($ptr, $len, __REGIONS, $lifetime)
+    }};
+}
+
+/// For mutable indexing of slices with multiple indices/ranges,
+/// also returning the parts of the slice that aren't covered by any argument.
+///
+/// This is like [`multindex_mut`], except that it returns a
+/// `(parts, uncovered)` pair, where:
+///
+/// - `parts` is the tuple that [`multindex_mut`] returns for the same arguments.
+///
+/// - `uncovered` is an array of `&mut [T]`, with the regions of the slice
+///   that aren't covered by any argument, in ascending order.
+///   The regions between the arguments are computed at compile-time,
+///   and the array has a slice for each non-empty one of them.
+///   Unless an unbounded range (eg: `4..`) covers the end of the slice,
+///   the last slice is the (possibly empty) region after the last argument.
+///
+/// The arguments can be passed in any order,
+/// and `dyn` arguments aren't allowed.
+///
+/// This requires the "rust_1_57" feature.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Panics
+///
+/// This macro panics at runtime if the indices/ranges are
+/// out of bounds for the passed slice.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multindex_rest_mut;
+///
+/// let mut packet = [3u8, 5, 0, 0, 13, 21, 34];
+///
+/// // The checksum is the sum of every byte that isn't in the `2..4` range.
+/// let ((checksum,), [before, after]) = multindex_rest_mut!(packet; 2..4);
+/// assert_eq!(before, &mut [3, 5]);
+/// assert_eq!(after, &mut [13, 21, 34]);
+///
+/// let sum: u16 = before.iter().chain(&*after).map(|&x| u16::from(x)).sum();
+/// *checksum = sum.to_be_bytes();
+///
+/// assert_eq!(packet, [3, 5, 0, 76, 13, 21, 34]);
+///
+/// // The trailing `5..` range covers the end of the slice,
+/// // so the only uncovered regions are `0..1` and `3..5`.
+/// let ((_, _), uncovered) = multindex_rest_mut!(packet; 1..3, 5..);
+/// assert_eq!(uncovered, [&mut [3][..], &mut [76, 13][..]]);
+/// ```
+///
+/// [`multindex_mut`]: ./macro.multindex_mut.html
+#[macro_export]
+macro_rules! multindex_rest_mut {
+    ( $slice:expr; $(,)? ) => ({
+        use $crate::utils::BorrowSelf as _;
+
+        match $slice._11748397628858797803_borrow_self_mut() {
+            slice => ((), [&mut slice[..]]),
+        }
+    });
+    ( $slice:expr; $($args:tt)* ) => (
+        $crate::_index_impl!{
+            slice = $slice;
+            args[$($args)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                on_out_of_bounds = panic,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                slice_parts = SlicePartsMut,
+                index_method = index_ptr_mut,
+                rest = yes,
+                no_runtime = yes,
+            )
+        }
+    );
+}
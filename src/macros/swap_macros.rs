@@ -0,0 +1,541 @@
+/// Splits the arguments of the swapping macros on the `,` and `=>` separators,
+/// passing them to `$callback` as `(argument tokens)` groups, keeping the separators.
+///
+/// `dyn` and end-relative arguments are passed as tokens,
+/// so that `_index_impl` reports them as `RuntimeArgument` errors.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _split_swap_args {
+    (
+        @from_end $callback:ident!$prefix:tt
+        $prev:tt
+        [$($arg:tt)*]
+        [.. end - $end:tt $($rem:tt)*]
+    ) => {
+        $crate::_split_swap_args!{
+            @separator $callback!$prefix $prev [$($arg)* .. end - $end] [$($rem)*]
+        }
+    };
+    (
+        @from_end $callback:ident!$prefix:tt
+        $prev:tt
+        [$($arg:tt)*]
+        [.. end $($rem:tt)*]
+    ) => {
+        $crate::_split_swap_args!{
+            @separator $callback!$prefix $prev [$($arg)* .. end] [$($rem)*]
+        }
+    };
+    (
+        @from_end $callback:ident!$prefix:tt
+        $prev:tt
+        [$($arg:tt)*]
+        [.. $($rem:tt)*]
+    ) => {
+        $crate::_split_swap_args!{
+            @separator $callback!$prefix $prev [$($arg)* ..] [$($rem)*]
+        }
+    };
+    (
+        @from_end $callback:ident!$prefix:tt
+        $prev:tt
+        $arg:tt
+        $rem:tt
+    ) => {
+        $crate::_split_swap_args!{ @separator $callback!$prefix $prev $arg $rem }
+    };
+    (
+        @separator $callback:ident!$prefix:tt
+        [$($prev:tt)*]
+        [$($arg:tt)*]
+        [$(,)?]
+    ) => {
+        $crate::_split_swap_args!{ $callback!$prefix [$($prev)* ($($arg)*)] [] }
+    };
+    (
+        @separator $callback:ident!$prefix:tt
+        [$($prev:tt)*]
+        [$($arg:tt)*]
+        [, $($rem:tt)+]
+    ) => {
+        $crate::_split_swap_args!{ $callback!$prefix [$($prev)* ($($arg)*) ,] [$($rem)*] }
+    };
+    (
+        @separator $callback:ident!$prefix:tt
+        [$($prev:tt)*]
+        [$($arg:tt)*]
+        [=> $($rem:tt)+]
+    ) => {
+        $crate::_split_swap_args!{ $callback!$prefix [$($prev)* ($($arg)*) =>] [$($rem)*] }
+    };
+    (
+        $callback:ident!{$($prefix:tt)*}
+        $prev:tt
+        []
+    ) => {
+        $crate::$callback!{$($prefix)* $prev}
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        [end - $start:tt $($rem:tt)*]
+    ) => {
+        $crate::_split_swap_args!{
+            @from_end $callback!$prefix $prev [end - $start] [$($rem)*]
+        }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        [dyn $index:expr $(,)?]
+    ) => {
+        $crate::_split_swap_args!{ @separator $callback!$prefix $prev [dyn $index] [] }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        [dyn $index:expr, $($rem:tt)+]
+    ) => {
+        $crate::_split_swap_args!{ @separator $callback!$prefix $prev [dyn $index] [, $($rem)*] }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        [dyn $index:expr => $($rem:tt)+]
+    ) => {
+        $crate::_split_swap_args!{ @separator $callback!$prefix $prev [dyn $index] [=> $($rem)*] }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        [$index:expr $(,)?]
+    ) => {
+        $crate::_split_swap_args!{ @separator $callback!$prefix $prev [$index] [] }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        [$index:expr, $($rem:tt)+]
+    ) => {
+        $crate::_split_swap_args!{ @separator $callback!$prefix $prev [$index] [, $($rem)*] }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        [$index:expr => $($rem:tt)+]
+    ) => {
+        $crate::_split_swap_args!{ @separator $callback!$prefix $prev [$index] [=> $($rem)*] }
+    };
+}
+
+/// For swapping the contents of two indices/ranges of a slice.
+///
+/// The arguments are checked at compile-time to be
+/// the same kind of argument (both indices, or both bounded ranges),
+/// of the same length, and not to overlap.
+///
+/// `dyn` arguments and arguments relative to the end of the slice (eg: `end - 4..`)
+/// aren't supported, because their lengths are only known at runtime.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Panics
+///
+/// This macro panics at runtime if the indices/ranges are
+/// out of bounds for the passed slice.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multiswap;
+///
+/// let mut arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+///
+/// multiswap!(arr; 0..4, 8..12);
+/// assert_eq!(arr, [8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3]);
+///
+/// multiswap!(arr; 4, 7);
+/// assert_eq!(arr, [8, 9, 10, 11, 7, 5, 6, 4, 0, 1, 2, 3]);
+/// ```
+///
+/// This doesn't compile, because the ranges have different lengths.
+///
+/// ```compile_fail
+/// let mut arr = [0; 12];
+/// multindex::multiswap!(arr; 0..4, 8..11);
+/// ```
+///
+/// This doesn't compile, because the ranges overlap.
+///
+/// ```compile_fail
+/// let mut arr = [0; 12];
+/// multindex::multiswap!(arr; 0..4, 3..7);
+/// ```
+///
+/// This doesn't compile, because the length of `end - 4..` is only known at runtime.
+///
+/// ```compile_fail
+/// let mut arr = [0; 12];
+/// multindex::multiswap!(arr; 0..4, end - 4..);
+/// ```
+///
+#[macro_export]
+macro_rules! multiswap {
+    ( $slice:expr; $($args:tt)* ) => (
+        $crate::_split_swap_args!{ _multiswap_impl!{$slice;} [] [$($args)*] }
+    );
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _multiswap_impl {
+    ( $slice:expr; [($($left:tt)*) , ($($right:tt)*)] ) => {{
+        let (left, right) = $crate::_index_impl! {
+            slice = $slice;
+            args[$($left)*, $($right)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                on_out_of_bounds = panic,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                slice_parts = SlicePartsMut,
+                index_method = index_ptr_mut,
+                same_lengths = [[0, 1]],
+                no_runtime = yes,
+            )
+        };
+        $crate::pmr::SwapWith::swap_with(left, right);
+    }};
+}
+
+/// For rotating the contents of multiple indices/ranges of a slice,
+/// moving the contents of each argument into the next one,
+/// and the contents of the last argument into the first one.
+///
+/// The arguments are checked at compile-time to be
+/// the same kind of argument (all indices, or all bounded ranges),
+/// of the same length, and not to overlap.
+///
+/// `dyn` arguments and arguments relative to the end of the slice (eg: `end - 4..`)
+/// aren't supported, because their lengths are only known at runtime.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Panics
+///
+/// This macro panics at runtime if the indices/ranges are
+/// out of bounds for the passed slice.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multirotate;
+///
+/// let mut arr = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+///
+/// multirotate!(arr; 0..2, 3..5, 6..8);
+/// assert_eq!(arr, [6, 7, 2, 0, 1, 5, 3, 4, 8]);
+///
+/// multirotate!(arr; 8, 2, 5);
+/// assert_eq!(arr, [6, 7, 8, 0, 1, 2, 3, 4, 5]);
+/// ```
+///
+/// This doesn't compile, because the third range is shorter than the first.
+///
+/// ```compile_fail
+/// let mut arr = [0; 12];
+/// multindex::multirotate!(arr; 0..2, 3..5, 6..7);
+/// ```
+///
+#[macro_export]
+macro_rules! multirotate {
+    ( $slice:expr; $($args:tt)+ ) => (
+        $crate::_split_swap_args!{ _multirotate_impl!{$slice;} [] [$($args)*] }
+    );
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _multirotate_impl {
+    ( $slice:expr; [$($index:tt),+] ) => (
+        $crate::_multirotate_impl!{
+            $slice;
+            []
+            [$($index)*]
+            [
+                0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+                16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+                32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+                48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+            ]
+        }
+    );
+    (
+        $slice:expr;
+        [$($prev:tt)*]
+        [$index:tt $($rem_index:tt)*]
+        [$count:tt $($rem_count:tt)*]
+    ) => {
+        $crate::_multirotate_impl!{
+            $slice;
+            [$($prev)* ($count, $index)]
+            [$($rem_index)*]
+            [$($rem_count)*]
+        }
+    };
+    (
+        $slice:expr;
+        [(0, ($($first:tt)*)) $(($count:tt, ($($index:tt)*)))*]
+        []
+        $rem_count:tt
+    ) => ({
+        let dests = $crate::_index_impl!{
+            slice = $slice;
+            args[$($first)*, $($($index)*,)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                on_out_of_bounds = panic,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                slice_parts = SlicePartsMut,
+                index_method = index_ptr_mut,
+                same_lengths = [$([0, $count],)*],
+                no_runtime = yes,
+            )
+        };
+        // Each swap moves the contents of the first argument into the `$count` argument,
+        // and the previous contents of the `$count` argument into the first one.
+        $( $crate::pmr::SwapWith::swap_with(dests.0, dests.$count); )*
+    });
+}
+
+/// For copying multiple indices/ranges of a slice into other indices/ranges of it.
+///
+/// Each argument is a `source => destination` pair,
+/// where the source and destination are checked at compile-time to be
+/// the same kind of argument (both indices, or both bounded ranges),
+/// of the same length.
+///
+/// All sources and destinations are checked not to overlap at compile-time,
+/// like the arguments of [`multindex_mut`].
+///
+/// `dyn` arguments and arguments relative to the end of the slice (eg: `end - 4..`)
+/// aren't supported, because their lengths are only known at runtime.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Panics
+///
+/// This macro panics at runtime if the indices/ranges are
+/// out of bounds for the passed slice.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multicopy_within;
+///
+/// let mut arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+///
+/// multicopy_within!(arr; 0..4 => 8..12, 5 => 4);
+/// assert_eq!(arr, [0, 1, 2, 3, 5, 5, 6, 7, 0, 1, 2, 3]);
+/// ```
+///
+/// This doesn't compile, because the source is shorter than the destination.
+///
+/// ```compile_fail
+/// let mut arr = [0; 12];
+/// multindex::multicopy_within!(arr; 0..3 => 8..12);
+/// ```
+///
+/// This doesn't compile, because a source is also a destination.
+///
+/// ```compile_fail
+/// let mut arr = [0; 12];
+/// multindex::multicopy_within!(arr; 0..2 => 2..4, 2..4 => 4..6);
+/// ```
+///
+/// [`multindex_mut`]: ./macro.multindex_mut.html
+#[macro_export]
+macro_rules! multicopy_within {
+    ( $slice:expr; $($args:tt)* ) => (
+        $crate::_split_swap_args!{ _multicopy_within_impl!{$slice;} [] [$($args)*] }
+    );
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _multicopy_within_impl {
+    ( $slice:expr; [$($src:tt => $dst:tt),*] ) => (
+        $crate::_multicopy_within_impl!{
+            $slice;
+            []
+            [$($src $dst)*]
+            [
+                0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+                16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+                32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+                48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+            ]
+        }
+    );
+    (
+        $slice:expr;
+        [$($prev:tt)*]
+        [$src:tt $dst:tt $($rem_index:tt)*]
+        [$src_count:tt $dst_count:tt $($rem_count:tt)*]
+    ) => {
+        $crate::_multicopy_within_impl!{
+            $slice;
+            [$($prev)* ($src_count, $src, $dst_count, $dst)]
+            [$($rem_index)*]
+            [$($rem_count)*]
+        }
+    };
+    (
+        $slice:expr;
+        [$(($src_count:tt, ($($src:tt)*), $dst_count:tt, ($($dst:tt)*)))*]
+        []
+        $rem_count:tt
+    ) => ({
+        let dests = $crate::_index_impl!{
+            slice = $slice;
+            args[$($($src)*, $($dst)*,)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                on_out_of_bounds = panic,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                slice_parts = SlicePartsMut,
+                index_method = index_ptr_mut,
+                same_lengths = [$([$src_count, $dst_count],)*],
+                no_runtime = yes,
+            )
+        };
+        $( $crate::pmr::copy_from(dests.$dst_count, dests.$src_count); )*
+    });
+}
+
+/// For reordering the elements at the start of a slice in place,
+/// with a permutation that's checked at compile-time.
+///
+/// The argument is an array of indices, where the element at each position `i`
+/// is moved from the position in the `i`th index,
+/// eg: `permute!(rgb; [2, 1, 0])` swaps the first and third elements.
+///
+/// The indices are checked to be a permutation of `0..N`
+/// (where `N` is the amount of indices) at compile-time,
+/// erroring if an index is duplicated (like overlapping arguments of [`multindex_mut`]),
+/// or is out of that range.
+///
+/// This moves the elements with swaps, so it doesn't require them to implement `Clone`.
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Panics
+///
+/// This macro panics at runtime if the slice is shorter than the amount of indices.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::permute;
+///
+/// let mut pixel = [10u8, 20, 30, 255];
+/// permute!(pixel; [2, 0, 1, 3]);
+/// assert_eq!(pixel, [30, 10, 20, 255]);
+///
+/// // Elements after the permuted ones are left as is.
+/// let mut words = vec!["world", "hello", "!"];
+/// permute!(words; [1, 0]);
+/// assert_eq!(words, ["hello", "world", "!"]);
+///
+/// ```
+///
+/// This doesn't compile, because `0` is repeated.
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 4];
+/// multindex::permute!(arr; [0, 1, 0, 3]);
+/// ```
+///
+/// This doesn't compile, because `4` isn't a position in the permutation.
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 8];
+/// multindex::permute!(arr; [0, 4, 1, 2]);
+/// ```
+///
+/// [`multindex_mut`]: ./macro.multindex_mut.html
+#[macro_export]
+macro_rules! permute {
+    ( $slice:expr; [$($index:expr),* $(,)?] $(,)? ) => (
+        $crate::_permute_impl!{
+            $slice;
+            []
+            [$($index,)*]
+            [
+                0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+                16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+                32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+                48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+                64
+            ]
+        }
+    );
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _permute_impl {
+    (
+        $slice:expr;
+        [$($prev:tt)*]
+        [$index:expr, $($rem_index:tt)*]
+        [$count:tt $($rem_count:tt)*]
+    ) => {
+        $crate::_permute_impl!{
+            $slice;
+            [$($prev)* $index,]
+            [$($rem_index)*]
+            [$($rem_count)*]
+        }
+    };
+    (
+        $slice:expr;
+        [$($index:expr,)*]
+        []
+        [$index_arg_count:tt $($rem_count:tt)*]
+    ) => ({
+        $crate::_declare_comp_consts!{
+            args = [$((static, $index))*],
+            index_arg_count = $index_arg_count,
+            expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+            check = $crate::pmr::IndexArgument::check_permutation,
+        }
+
+        use $crate::utils::BorrowSelf as _;
+
+        match $slice._11748397628858797803_borrow_self_mut() { slice => {
+            let slice: &mut [_] = slice;
+
+            if __COMP_CONSTS.stats.max_bounded_end > slice.len() {
+                $crate::pmr::panic_on_oob_max_index(__COMP_CONSTS.stats.max_bounded_end, slice.len());
+            }
+
+            $crate::pmr::permute_in_place(slice, &__COMP_CONSTS.ind_args);
+        }}
+    });
+}
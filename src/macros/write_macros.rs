@@ -0,0 +1,214 @@
+/// Splits the `index = value` and `index => value` arguments of the writing macros,
+/// passing them to `$callback` as `(count (index tokens) operator value)` tuples.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _parse_write_args {
+    (
+        $callback:ident!{$($prefix:tt)*}
+        [$($prev:tt)*]
+        [$($curr:tt)+]
+        [= $value:expr $(, $($rem:tt)*)?]
+    ) => {
+        $crate::_parse_write_args!{
+            $callback!{$($prefix)*}
+            [$($prev)* (($($curr)*) = $value)]
+            []
+            [$($($rem)*)?]
+        }
+    };
+    (
+        $callback:ident!{$($prefix:tt)*}
+        [$($prev:tt)*]
+        [$($curr:tt)+]
+        [=> $value:expr $(, $($rem:tt)*)?]
+    ) => {
+        $crate::_parse_write_args!{
+            $callback!{$($prefix)*}
+            [$($prev)* (($($curr)*) => $value)]
+            []
+            [$($($rem)*)?]
+        }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        [$($curr:tt)*]
+        [$token:tt $($rem:tt)*]
+    ) => {
+        $crate::_parse_write_args!{
+            $callback!$prefix
+            $prev
+            [$($curr)* $token]
+            [$($rem)*]
+        }
+    };
+    (
+        $callback:ident!$prefix:tt
+        $prev:tt
+        [$($curr:tt)+]
+        []
+    ) => {
+        $crate::pmr::compile_error!{
+            $crate::pmr::concat!(
+                "expected `= value` or `=> value` after the `",
+                $crate::pmr::stringify!($($curr)*),
+                "` argument",
+            )
+        }
+    };
+    (
+        $callback:ident!$prefix:tt
+        [$($args:tt)*]
+        []
+        []
+    ) => {
+        $crate::_parse_write_args!{
+            @count
+            $callback!$prefix
+            []
+            [$($args)*]
+            [
+                0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+                16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+                32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+                48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+                64
+            ]
+        }
+    };
+    (@count
+        $callback:ident!$prefix:tt
+        [$($prev:tt)*]
+        [($($arg:tt)*) $($rem_args:tt)*]
+        [$count:tt $($rem_count:tt)*]
+    ) => {
+        $crate::_parse_write_args!{
+            @count
+            $callback!$prefix
+            [$($prev)* ($count $($arg)*)]
+            [$($rem_args)*]
+            [$($rem_count)*]
+        }
+    };
+    (@count
+        $callback:ident!{$($prefix:tt)*}
+        $args:tt
+        []
+        $rem_count:tt
+    ) => {
+        $crate::$callback!{$($prefix)* $args}
+    };
+}
+
+/// Writes `$value` into `$dest`, a mutable reference returned by the indexing macros.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _write_arg {
+    (=, $dest:expr, $value:expr) => {
+        $crate::pmr::Put::put($dest, $value)
+    };
+    (=>, $dest:expr, $value:expr) => {
+        $crate::pmr::Fill::fill($dest, $value)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _multiwrite_impl {
+    (
+        ($slice:expr)
+        [$( ($count:tt ($($index:tt)*) $op:tt $value:expr) )*]
+    ) => ({
+        let dests = $crate::_index_impl!{
+            slice = $slice;
+            args[$($($index)*,)*];
+            (
+                expected_are_disjoint = $crate::pmr::AreAllDisjoint::YES,
+                on_out_of_bounds = panic,
+                auto_borrow_method = _11748397628858797803_borrow_self_mut,
+                slice_parts = SlicePartsMut,
+                index_method = index_ptr_mut,
+            )
+        };
+        $( $crate::_write_arg!($op, dests.$count, $value); )*
+    });
+}
+
+/// For writing values into multiple indices/ranges of a slice.
+///
+/// Each argument is followed by either:
+///
+/// - `= value`: which moves the value into the argument.
+///   Integer arguments take an element,
+///   bounded ranges take an array as long as the range
+///   (a different length is a compile-time error),
+///   trailing unbounded ranges and `dyn` ranges take anything that derefs to a slice
+///   (with `AsRef<[T]>`), cloning its elements,
+///   and typed arguments (eg: `u32be @ 0..4`) take the integer/float they encode.
+///
+/// - `=> value`: which overwrites every element of the argument with clones of the value.
+///   Without the "rust_1_57" feature,
+///   bounded ranges are limited to the array lengths that [`Pod`] is implemented for.
+///
+/// This checks that all arguments are in bounds before writing any value,
+/// then evaluates and writes the values in order.
+///
+/// The arguments are checked not to overlap at compile-time,
+/// like the arguments of [`multindex_mut`].
+///
+/// # Shared docs
+///
+/// The indexing macros share a lot in common,
+/// because of that [you can look here](./indexing_macro_docs/index.html)
+/// for additional documentation.
+///
+/// # Panics
+///
+/// This macro panics at runtime if the indices/ranges are
+/// out of bounds for the passed slice,
+/// or if the slice written into a trailing unbounded range or `dyn` range
+/// has a different length than the range.
+///
+/// # Example
+///
+/// ```rust
+/// use multindex::multiwrite;
+///
+/// let mut arr = [0u8; 12];
+///
+/// let rest_src = vec![7, 8, 9, 10];
+/// multiwrite!(arr; 0 = 1, 2..5 = [2, 3, 4], 8.. = &rest_src);
+/// assert_eq!(arr, [1, 0, 2, 3, 4, 0, 0, 0, 7, 8, 9, 10]);
+///
+/// // Zeroing sections
+/// multiwrite!(arr; 2..10 => 0, u16be @ 0..2 = 0xABCD);
+/// assert_eq!(arr, [0xAB, 0xCD, 0, 0, 0, 0, 0, 0, 0, 0, 9, 10]);
+/// ```
+///
+/// This doesn't compile, because the first and second arguments overlap.
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 12];
+/// multindex::multiwrite!(arr; 0..4 => 0, 3 = 1);
+/// ```
+///
+/// This doesn't compile, because the range is 3 elements long, and the array 2.
+///
+/// ```compile_fail
+/// let mut arr = [0u8; 12];
+/// multindex::multiwrite!(arr; 0..3 = [1, 2]);
+/// ```
+///
+/// [`Pod`]: ./pod/trait.Pod.html
+/// [`multindex_mut`]: ./macro.multindex_mut.html
+#[macro_export]
+macro_rules! multiwrite {
+    ( $slice:expr; $($args:tt)* ) => (
+        $crate::_parse_write_args!{
+            _multiwrite_impl!{($slice)}
+            []
+            []
+            [$($args)*]
+        }
+    );
+}
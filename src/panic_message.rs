@@ -0,0 +1,279 @@
+//! A buffer for building panic messages in const fns,
+//! since const panics can only print a `&str`.
+
+use crate::{
+    bits::MAX_FIELD_BITS,
+    error::{Error, OutOfBounds},
+    index_argument::{IndexArgument, IndexKind, PrenormIndex},
+};
+
+const CAPACITY: usize = 320;
+
+pub(crate) struct PanicMessage {
+    bytes: [u8; CAPACITY],
+    len: usize,
+}
+
+impl PanicMessage {
+    pub(crate) const fn new() -> Self {
+        Self {
+            bytes: [0; CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// Appends `s`, truncating the message if it's too long.
+    pub(crate) const fn str(mut self, s: &str) -> Self {
+        let s = s.as_bytes();
+        for_range! {i in 0..s.len() =>
+            if self.len == CAPACITY {
+                break;
+            }
+            self.bytes[self.len] = s[i];
+            self.len += 1;
+        }
+        self
+    }
+
+    pub(crate) const fn usize(mut self, mut n: usize) -> Self {
+        let mut digits = [0u8; 20];
+        let mut i = digits.len();
+        loop {
+            i -= 1;
+            digits[i] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        for_range! {i in i..digits.len() =>
+            if self.len == CAPACITY {
+                break;
+            }
+            self.bytes[self.len] = digits[i];
+            self.len += 1;
+        }
+        self
+    }
+
+    const fn opt_usize(self, n: Option<usize>) -> Self {
+        match n {
+            Some(n) => self.usize(n),
+            None => self,
+        }
+    }
+
+    /// Appends an argument as it was written in the macro invocation,
+    /// surrounded by backticks.
+    pub(crate) const fn prenorm(self, prenorm: &PrenormIndex) -> Self {
+        let this = self.str("`");
+        let this = match *prenorm {
+            PrenormIndex::Index(i) => this.usize(i),
+            PrenormIndex::Range { start, end } => this.opt_usize(start).str("..").opt_usize(end),
+            PrenormIndex::InclusiveToMax { start } => this.opt_usize(start).str("..=usize::MAX"),
+            PrenormIndex::Runtime => this.str("dyn _"),
+            PrenormIndex::IndexFromEnd(start) => this.str("end-").usize(start),
+            PrenormIndex::RangeFromEnd { start, end: 0 } => this.str("end-").usize(start).str(".."),
+            PrenormIndex::RangeFromEnd { start, end } => {
+                this.str("end-").usize(start).str("..end-").usize(end)
+            }
+        };
+        this.str("`")
+    }
+
+    /// Appends the range of indices that a normalized argument covers.
+    pub(crate) const fn ind_arg(self, ind_arg: &IndexArgument) -> Self {
+        let this = self.usize(ind_arg.start()).str("..");
+        match ind_arg.index_kind() {
+            IndexKind::RangeFrom => this,
+            IndexKind::Range | IndexKind::Index => this.usize(ind_arg.saturated_end()),
+        }
+    }
+
+    /// Appends `", covering "` followed by the range of indices that the argument covers,
+    /// unless it's relative to the end of the slice, whose range is only known at runtime.
+    const fn covering(self, prenorm: &PrenormIndex, ind_arg: &IndexArgument) -> Self {
+        if prenorm.is_from_end() {
+            self
+        } else {
+            self.str(", covering ").ind_arg(ind_arg)
+        }
+    }
+
+    pub(crate) const fn as_str(&self) -> &str {
+        let mut bytes: &[u8] = &self.bytes;
+        // Removing the unused part of the buffer.
+        while let [rem @ .., _] = bytes {
+            if rem.len() < self.len {
+                break;
+            }
+            bytes = rem;
+        }
+        // Safety: only ASCII strings are appended to the buffer,
+        // so truncating the message can't split a character.
+        unsafe { core::str::from_utf8_unchecked(bytes) }
+    }
+}
+
+impl Error {
+    /// Describes this error, using the arguments passed to the macro.
+    ///
+    /// `prenorm` are the arguments as written in the macro invocation,
+    /// and `ind_args` are their normalized versions.
+    pub(crate) const fn panic_message(
+        &self,
+        prenorm: &[PrenormIndex],
+        ind_args: &[IndexArgument],
+    ) -> PanicMessage {
+        let msg = PanicMessage::new().str("multindex error: ");
+
+        match *self {
+            Error::PrevEndIsUnbounded { current_index: i } => {
+                let i = i as usize;
+                msg.str("argument ")
+                    .usize(i)
+                    .str(" (")
+                    .prenorm(&prenorm[i])
+                    .str(") has an unbounded start, and the previous argument (")
+                    .prenorm(&prenorm[i - 1])
+                    .str(") has an unbounded end")
+            }
+            Error::NextStartIsUnbounded { current_index: i } => {
+                let i = i as usize;
+                msg.str("argument ")
+                    .usize(i)
+                    .str(" (")
+                    .prenorm(&prenorm[i])
+                    .str(") has an unbounded end, and the next argument (")
+                    .prenorm(&prenorm[i + 1])
+                    .str(") has an unbounded start")
+            }
+            Error::NextStartIsLessThanCurrent { current_index: i } => {
+                let i = i as usize;
+                msg.str("argument ")
+                    .usize(i)
+                    .str(" (")
+                    .prenorm(&prenorm[i])
+                    .str(") has an unbounded end, and the next argument (")
+                    .prenorm(&prenorm[i + 1])
+                    .str(") starts before it")
+            }
+            Error::InclusiveUptoUsizeMax { current_index: i } => {
+                let i = i as usize;
+                msg.str("argument ")
+                    .usize(i)
+                    .str(" (")
+                    .prenorm(&prenorm[i])
+                    .str(") ends at usize::MAX inclusive, which is not supported")
+            }
+            Error::OverlappingIndexArgs { left, right } => {
+                let (left, right) = (left as usize, right as usize);
+                msg.str("argument ")
+                    .usize(left)
+                    .str(" (")
+                    .prenorm(&prenorm[left])
+                    .covering(&prenorm[left], &ind_args[left])
+                    .str(") overlaps with argument ")
+                    .usize(right)
+                    .str(" (")
+                    .prenorm(&prenorm[right])
+                    .covering(&prenorm[right], &ind_args[right])
+                    .str(")")
+            }
+            Error::TypedArgumentLength {
+                current_index: i,
+                type_size,
+            } => {
+                let i = i as usize;
+                msg.str("argument ")
+                    .usize(i)
+                    .str(" (")
+                    .prenorm(&prenorm[i])
+                    .str(", covering ")
+                    .ind_arg(&ind_args[i])
+                    .str(") is a typed argument, which must be a range of ")
+                    .usize(type_size)
+                    .str(" elements")
+            }
+            Error::MismatchedLengths { left, right } => {
+                let (left, right) = (left as usize, right as usize);
+                msg.str("argument ")
+                    .usize(left)
+                    .str(" (")
+                    .prenorm(&prenorm[left])
+                    .str(", covering ")
+                    .ind_arg(&ind_args[left])
+                    .str(") and argument ")
+                    .usize(right)
+                    .str(" (")
+                    .prenorm(&prenorm[right])
+                    .str(", covering ")
+                    .ind_arg(&ind_args[right])
+                    .str(") must both be indices, or bounded ranges of the same length")
+            }
+            Error::NotAPermutation {
+                current_index: i,
+                len,
+            } => {
+                let i = i as usize;
+                msg.str("argument ")
+                    .usize(i)
+                    .str(" (")
+                    .prenorm(&prenorm[i])
+                    .str(") must be an index less than ")
+                    .usize(len as usize)
+                    .str(", the amount of arguments, for them to be a permutation")
+            }
+            Error::GapBeforeArgument {
+                current_index: i,
+                expected_start,
+            } => {
+                let i = i as usize;
+                msg.str("argument ")
+                    .usize(i)
+                    .str(" (")
+                    .prenorm(&prenorm[i])
+                    .str(", covering ")
+                    .ind_arg(&ind_args[i])
+                    .str(") must start at ")
+                    .usize(expected_start)
+                    .str(", leaving no gap before it")
+            }
+            Error::RuntimeArgument { current_index: i } => {
+                let i = i as usize;
+                msg.str("argument ")
+                    .usize(i)
+                    .str(" (")
+                    .prenorm(&prenorm[i])
+                    .str(") is a runtime argument, which this macro doesn't take")
+            }
+            Error::FieldTooWide {
+                current_index: i,
+                width,
+            } => {
+                let i = i as usize;
+                msg.str("argument ")
+                    .usize(i)
+                    .str(" (")
+                    .prenorm(&prenorm[i])
+                    .str(") is ")
+                    .usize(width)
+                    .str(" bits long, bit fields can't be longer than ")
+                    .usize(MAX_FIELD_BITS)
+                    .str(" bits")
+            }
+            Error::OutOfBounds(OutOfBounds {
+                slice_len,
+                max_bounded_end,
+                argument,
+            }) => msg
+                .str("argument ")
+                .usize(argument as usize)
+                .str(" requires a slice of at least ")
+                .usize(max_bounded_end)
+                .str(" elements, but the slice has ")
+                .usize(slice_len)
+                .str(" elements"),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Casting byte ranges to plain-old-data types with the indexing macros.
+//!
+//! A cast argument is `cast<T> @` followed by a range as long as `T`,
+//! eg: `cast<Header> @ 0..16`,
+//! where `T` implements the [`Pod`] trait.
+//!
+//! The shared indexing macros (eg: [`multindex`]) return a `&T`,
+//! while the mutable ones (eg: [`multindex_mut`]) return a `&mut T`.
+//!
+//! It's a compile-time error for the range to have a different length than `T`,
+//! or for the indexed slice to not be a `[u8]`.
+//!
+//! # Panics
+//!
+//! The indexing macros panic if a cast argument isn't aligned to the alignment of `T`,
+//! this includes the fallible macros (eg: [`multiget`]).
+//!
+//! # Example
+//!
+//! ```rust
+//! use multindex::{multindex, multindex_mut, pod::Pod};
+//!
+//! #[repr(C)]
+//! #[derive(Debug, Copy, Clone, PartialEq)]
+//! struct Header {
+//!     kind: [u8; 2],
+//!     len: [u8; 2],
+//! }
+//!
+//! unsafe impl Pod for Header {}
+//!
+//! let mut packet = [1u8, 0, 0, 3, 10, 20, 30];
+//!
+//! let (header, payload) = multindex!(packet; cast<Header> @ 0..4, ..);
+//! assert_eq!(header, &Header { kind: [1, 0], len: [0, 3] });
+//! assert_eq!(payload, &[10, 20, 30][..]);
+//!
+//! let (header,) = multindex_mut!(packet; cast<Header> @ ..4);
+//! header.len = [0, 2];
+//! assert_eq!(packet[..4], [1, 0, 0, 2]);
+//!
+//! ```
+//!
+//! This doesn't compile, because `Header` is 4 bytes long,
+//! and the range is 3 bytes long.
+//!
+//! ```compile_fail
+//! # use multindex::pod::Pod;
+//! #[repr(C)]
+//! #[derive(Copy, Clone)]
+//! struct Header {
+//!     kind: [u8; 2],
+//!     len: [u8; 2],
+//! }
+//!
+//! unsafe impl Pod for Header {}
+//!
+//! let packet = [1u8, 0, 0, 3, 10, 20, 30];
+//! let _ = multindex::multindex!(packet; cast<Header> @ 0..3);
+//! ```
+//!
+//! [`multindex`]: ../macro.multindex.html
+//! [`multindex_mut`]: ../macro.multindex_mut.html
+//! [`multiget`]: ../macro.multiget.html
+
+use crate::endian::{
+    f32be, f32le, f64be, f64le, i128be, i128le, i16be, i16le, i32be, i32le, i64be, i64le, u128be,
+    u128le, u16be, u16le, u32be, u32le, u64be, u64le,
+};
+
+/// Marker trait for types that can be cast from and to bytes.
+///
+/// # Safety
+///
+/// Implementors must be valid for any bit pattern,
+/// and must not have padding bytes
+/// (writing a type with padding bytes through a `&mut T` casted from bytes
+/// would leave uninitialized bytes in the byte slice).
+///
+/// This means that types that implement this trait
+/// can't contain references, pointers, `bool`s, `char`s, or enums,
+/// and structs must be `#[repr(C)]` or `#[repr(transparent)]`, with all fields being `Pod`.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($ty:ty)*) => {
+        $( unsafe impl Pod for $ty {} )*
+    };
+}
+
+impl_pod! {
+    u8 u16 u32 u64 u128 usize
+    i8 i16 i32 i64 i128 isize
+    f32 f64
+    u16be u16le u32be u32le u64be u64le u128be u128le
+    i16be i16le i32be i32le i64be i64le i128be i128le
+    f32be f32le f64be f64le
+}
+
+macro_rules! impl_pod_array {
+    ($($len:literal)*) => {
+        $( unsafe impl<T: Pod> Pod for [T; $len] {} )*
+    };
+}
+
+impl_pod_array! {
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+    16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+    32 48 64 96 128 256 512 1024 2048 4096
+}
+
+/// Panics if the pointer to the byte at `index` isn't aligned for `T`.
+///
+/// This also ensures that the type of cast arguments implements `Pod`.
+#[doc(hidden)]
+#[inline(always)]
+// `usize::is_multiple_of` was stabilized in Rust 1.87, after the MSRV of this crate.
+#[allow(clippy::manual_is_multiple_of)]
+pub fn check_cast_alignment<T: Pod>(base: *const u8, index: usize, argument: u16) {
+    let address = base as usize + index;
+    let align = core::mem::align_of::<T>();
+    if address % align != 0 {
+        panic_on_misaligned_cast(argument, address, align)
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn panic_on_misaligned_cast(argument: u16, address: usize, align: usize) -> ! {
+    panic!(
+        "argument {} is at address {:#x}, which isn't aligned to {} bytes",
+        argument, address, align,
+    );
+}
@@ -1,4 +1,6 @@
-use crate::index_argument::{IK_Index, IK_Range, IK_RangeFrom};
+use crate::index_argument::{IK_Index, IK_Range, IK_RangeClamped, IK_RangeFrom};
+
+use crate::std_const_fns::usize_m;
 
 use core::marker::PhantomData;
 
@@ -16,6 +18,18 @@ impl IndexerParams {
             _marker: PhantomData,
         }
     }
+
+    // `Indexer` contains a function pointer type,
+    // which isn't allowed in const fns before Rust 1.61.
+    #[cfg(feature = "rust_1_64")]
+    #[inline(always)]
+    pub const fn build_const<Elem, RetArray, IK>(self) -> Indexer<Elem, RetArray, IK> {
+        Indexer {
+            index: self.index,
+            slice_len: self.slice_len,
+            _marker: PhantomData,
+        }
+    }
 }
 
 pub struct Indexer<Elem, RetArray, IK> {
@@ -114,3 +128,54 @@ impl<T, RetArray> IndexPointer for Indexer<T, RetArray, IK_RangeFrom> {
         )
     }
 }
+
+/// `slice_len` is the end of the range, clamped to the length of the slice,
+/// the start of the range is clamped to `slice_len` while indexing.
+impl<T, RetArray> IndexPointer for Indexer<T, RetArray, IK_RangeClamped> {
+    type Elem = T;
+    type Output = [T];
+
+    #[inline(always)]
+    unsafe fn index_ptr(self, base: *const Self::Elem, _: PhantomData<&T>) -> &Self::Output {
+        let start = usize_m::min(self.index as usize, self.slice_len);
+        core::slice::from_raw_parts(base.add(start), self.slice_len - start)
+    }
+
+    #[inline(always)]
+    unsafe fn index_ptr_mut(
+        self,
+        base: *mut Self::Elem,
+        _: PhantomData<&mut T>,
+    ) -> &mut Self::Output {
+        let start = usize_m::min(self.index as usize, self.slice_len);
+        core::slice::from_raw_parts_mut(base.add(start), self.slice_len - start)
+    }
+}
+
+/// Const equivalents of `IndexPointer::index_ptr`, used by `multindex_const`.
+#[cfg(feature = "rust_1_64")]
+impl<T, RetArray> Indexer<T, RetArray, IK_Index> {
+    #[inline(always)]
+    pub const unsafe fn index_ptr_const(self, base: *const T, _: PhantomData<&T>) -> &T {
+        &*base.offset(self.index)
+    }
+}
+
+#[cfg(feature = "rust_1_64")]
+impl<T, RetArray> Indexer<T, RetArray, IK_Range> {
+    #[inline(always)]
+    pub const unsafe fn index_ptr_const(self, base: *const T, _: PhantomData<&T>) -> &RetArray {
+        &*(base.offset(self.index) as *const RetArray)
+    }
+}
+
+#[cfg(feature = "rust_1_64")]
+impl<T, RetArray> Indexer<T, RetArray, IK_RangeFrom> {
+    #[inline(always)]
+    pub const unsafe fn index_ptr_const(self, base: *const T, _: PhantomData<&T>) -> &[T] {
+        core::slice::from_raw_parts(
+            base.offset(self.index),
+            self.slice_len - self.index as usize,
+        )
+    }
+}
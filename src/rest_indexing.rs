@@ -0,0 +1,46 @@
+//! Getting the parts of a slice that aren't covered by any argument,
+//! used by the `multindex_rest_mut` macro.
+
+use crate::index_argument::IndexArgument;
+
+use core::{marker::PhantomData, slice};
+
+/// Gets the `N` regions of a slice that aren't covered by any of `ind_args`,
+/// in ascending order.
+///
+/// `N` must be `IndexArgument::count_uncovered(ind_args)`.
+pub const fn uncovered_regions<const N: usize>(ind_args: &[IndexArgument]) -> [IndexArgument; N] {
+    let mut regions = [IndexArgument::EMPTY; N];
+    let mut from = 0;
+    for_range! {i in 0..N =>
+        regions[i] = match IndexArgument::next_uncovered(ind_args, from) {
+            Some(region) => region,
+            None => panic!("expected N to be the amount of uncovered regions"),
+        };
+        from = regions[i].saturated_end();
+    }
+    regions
+}
+
+/// Gets mutable references to the `regions` of the slice that `ptr` and `len` came from.
+///
+/// # Safety
+///
+/// `regions` must be in bounds of the slice and not overlap with each other,
+/// nor with any other reference derived from the slice.
+/// The trailing `IndexKind::RangeFrom` region must start at or before `len`.
+#[inline(always)]
+pub unsafe fn uncovered_slices_mut<T, const N: usize>(
+    ptr: *mut T,
+    len: usize,
+    regions: [IndexArgument; N],
+    _: PhantomData<&mut T>,
+) -> [&mut [T]; N] {
+    regions.map(|region| {
+        let end = match region.end() {
+            Some(end) => end,
+            None => len,
+        };
+        slice::from_raw_parts_mut(ptr.add(region.start()), end - region.start())
+    })
+}
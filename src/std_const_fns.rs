@@ -51,6 +51,15 @@ pub mod usize_m {
             l - r
         }
     }
+
+    pub const fn saturating_add(l: usize, r: usize) -> usize {
+        let (sum, overflowed) = l.overflowing_add(r);
+        if overflowed {
+            usize::MAX
+        } else {
+            sum
+        }
+    }
 }
 
 pub mod option_m {
@@ -1,3 +1,8 @@
+use crate::{error::Error, index_argument::IndexArgument};
+
+#[cfg(feature = "rust_1_57")]
+use crate::{error::OutOfBounds, index_properties::IndexArgumentStats};
+
 use core::marker::PhantomData;
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -76,6 +81,13 @@ impl<'a, T> SlicePartsMut<'a, T> {
     }
 }
 
+/// Gets the lifetime of shared references into a mutably borrowed slice,
+/// used by `multindex_mixed` for its `ref` arguments.
+#[inline(always)]
+pub fn shared_lifetime<T>(_: PhantomData<&mut T>) -> PhantomData<&T> {
+    PhantomData
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 /// Error message when the maximum exclusive end is outside the bounds of the slice.
@@ -87,3 +99,66 @@ pub fn panic_on_oob_max_index(maximum_index: usize, slice_len: usize) -> ! {
         maximum_index, slice_len,
     );
 }
+
+/// Error message when the arguments of `multindex_const` are out of bounds,
+/// which is a compile-time error when it's evaluated in a constant.
+#[cfg(feature = "rust_1_57")]
+#[cold]
+#[inline(never)]
+pub const fn const_panic_on_oob(stats: &IndexArgumentStats, slice_len: usize) -> ! {
+    let error = Error::OutOfBounds(OutOfBounds {
+        slice_len,
+        max_bounded_end: stats.max_bounded_end,
+        argument: stats.max_bounded_end_arg,
+    });
+    error.panic(&[], &[])
+}
+
+/// Error message when the runtime arguments of the indexing macros are invalid.
+#[cold]
+#[inline(never)]
+pub fn panic_on_dyn_error(error: Error) -> ! {
+    panic!("Invalid runtime index argument: {:?}", error);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Gets the size of a struct field from a function that borrows it,
+/// used by `layout_of` to compute the span of each field.
+#[cfg(feature = "rust_1_77")]
+pub const fn field_size<S, F>(_: fn(&S) -> &F) -> usize {
+    core::mem::size_of::<F>()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Reorders `slice` so that the element at each position `i`
+/// is the one that was at `permutation[i].start()`, used by the `permute` macro.
+///
+/// `permutation` must be a permutation of the indices `0..permutation.len()`,
+/// and `slice` must be at least that long.
+pub fn permute_in_place<T>(slice: &mut [T], permutation: &[IndexArgument]) {
+    let at = |i: usize| permutation[i].start();
+
+    for start in 0..permutation.len() {
+        // Only applying each cycle of the permutation once,
+        // from its smallest position.
+        let mut i = at(start);
+        while i > start {
+            i = at(i);
+        }
+        if i < start {
+            continue;
+        }
+
+        let mut curr = start;
+        loop {
+            let next = at(curr);
+            if next == start {
+                break;
+            }
+            slice.swap(curr, next);
+            curr = next;
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Traits for reading values from, and writing values into,
+//! the references returned by the indexing macros,
+//! used by the [`put`], [`multiwrite`], [`gather`], and [`scatter`] macros.
+//!
+//! [`put`]: ../macro.put.html
+//! [`multiwrite`]: ../macro.multiwrite.html
+//! [`gather`]: ../macro.gather.html
+//! [`scatter`]: ../macro.scatter.html
+
+/// Gets an owned value from the output of the shared indexing macros,
+/// used by the `gather` macro.
+///
+/// This is implemented for references to `Clone` types,
+/// and for the values that typed arguments (eg: `u32be @ 0..4`) return.
+pub trait Gather {
+    type Output;
+
+    fn gather(self) -> Self::Output;
+}
+
+impl<T: Clone> Gather for &T {
+    type Output = T;
+
+    #[inline(always)]
+    fn gather(self) -> T {
+        self.clone()
+    }
+}
+
+macro_rules! impl_gather_value {
+    ($($ty:ty)*) => {
+        $(
+            impl Gather for $ty {
+                type Output = $ty;
+
+                #[inline(always)]
+                fn gather(self) -> $ty {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_gather_value! { u16 u32 u64 u128 i16 i32 i64 i128 f32 f64 }
+
+/// Writes a value of type `V` into `Self`, used for `index = value` arguments.
+///
+/// This is implemented for:
+///
+/// - All types with `V == Self`, moving the value.
+///
+/// - `[T]` (from trailing unbounded ranges), with any `AsRef<[T]>`,
+///   cloning the elements of the value,
+///   which panics if the value has a different length than `Self`.
+///
+/// - The types in the `endian` module, with the integer/float they encode.
+pub trait Put<V> {
+    fn put(&mut self, value: V);
+}
+
+impl<T> Put<T> for T {
+    #[inline(always)]
+    fn put(&mut self, value: T) {
+        *self = value;
+    }
+}
+
+impl<T, S> Put<S> for [T]
+where
+    T: Clone,
+    S: AsRef<[T]>,
+{
+    #[inline]
+    fn put(&mut self, value: S) {
+        self.clone_from_slice(value.as_ref());
+    }
+}
+
+/// Overwrites every element of `Self` with a clone of a `V`,
+/// used for `index => value` arguments.
+///
+/// This is implemented for elements, slices,
+/// and arrays (of every length with the "rust_1_57" feature,
+/// otherwise the lengths that `Pod` is implemented for).
+pub trait Fill<V> {
+    fn fill(&mut self, value: V);
+}
+
+impl<T> Fill<T> for T {
+    #[inline(always)]
+    fn fill(&mut self, value: T) {
+        *self = value;
+    }
+}
+
+impl<T: Clone> Fill<T> for [T] {
+    #[inline]
+    fn fill(&mut self, value: T) {
+        for elem in self {
+            elem.clone_from(&value);
+        }
+    }
+}
+
+#[cfg(feature = "rust_1_57")]
+impl<T: Clone, const N: usize> Fill<T> for [T; N] {
+    #[inline]
+    fn fill(&mut self, value: T) {
+        Fill::fill(&mut self[..], value)
+    }
+}
+
+#[cfg(not(feature = "rust_1_57"))]
+macro_rules! impl_fill_array {
+    ($($len:literal)*) => {
+        $(
+            impl<T: Clone> Fill<T> for [T; $len] {
+                #[inline]
+                fn fill(&mut self, value: T) {
+                    Fill::fill(&mut self[..], value)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "rust_1_57"))]
+impl_fill_array! {
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+    16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+    32 48 64 96 128 256 512 1024 2048 4096
+}
+
+/// Swaps the contents of `self` and `other`,
+/// used by the `multiswap` and `multirotate` macros.
+///
+/// This is implemented for sized types and slices,
+/// slices only get here from `dyn` ranges, which those macros reject at compile-time,
+/// so that the only error for them is the `RuntimeArgument` one.
+pub trait SwapWith {
+    fn swap_with(&mut self, other: &mut Self);
+}
+
+impl<T> SwapWith for T {
+    #[inline(always)]
+    fn swap_with(&mut self, other: &mut T) {
+        core::mem::swap(self, other);
+    }
+}
+
+impl<T> SwapWith for [T] {
+    #[inline]
+    fn swap_with(&mut self, other: &mut [T]) {
+        self.swap_with_slice(other);
+    }
+}
+
+/// Copies `src` into `dest`, used by the `multicopy_within` macro.
+#[inline(always)]
+pub fn copy_from<T: Copy>(dest: &mut T, src: &T) {
+    *dest = *src;
+}
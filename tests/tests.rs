@@ -1,6 +1,33 @@
 mod tests_mod {
+    #[cfg(feature = "rust_1_57")]
+    mod array_indexing_tests;
     mod automatic_indexing_tests;
+    mod bit_indexing_tests;
+    mod cast_indexing_tests;
+    mod clamped_indexing_tests;
+    #[cfg(feature = "rust_1_64")]
+    mod const_indexing_tests;
+    mod cursor_tests;
+    mod dyn_indexing_tests;
+    mod each_indexing_tests;
     mod error_examples;
+    mod exhaustive_indexing_tests;
+    mod from_end_indexing_tests;
+    mod gather_tests;
+    #[cfg(feature = "rust_1_57")]
+    mod generic_indexing_tests;
     mod indexing_examples;
+    mod layout_tests;
+    mod mixed_indexing_tests;
+    mod mixed_mutability_tests;
+    mod multiwrite_tests;
+    mod permute_tests;
     mod range_conversion_examples;
+    #[cfg(feature = "rust_1_57")]
+    mod rest_indexing_tests;
+    #[cfg(feature = "rust_1_77")]
+    mod struct_layout_tests;
+    mod swap_tests;
+    mod typed_indexing_tests;
+    mod write_cursor_tests;
 }
@@ -0,0 +1,155 @@
+use multindex::{multindex_arr, multindex_arr_exhaustive_mut, multindex_arr_mut, multisplit};
+
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn array_indexing() {
+    let mut arr = [3u16, 5, 8, 13, 21, 34, 55];
+
+    assert_eq!(multindex_arr!(arr; 2), (&8,));
+    assert_eq!(multindex_arr!(arr; 2..4), (&[8, 13],));
+    assert_eq!(multindex_arr!(arr; ..=1, 6), (&[3, 5], &55));
+
+    let (first, mid, rest): (_, _, &[u16; 3]) = multindex_arr!(arr; 0, 1..4, ..);
+    assert_eq!((first, mid, rest), (&3, &[5, 8, 13], &[21, 34, 55]));
+
+    let (all,): (&[u16; 7],) = multindex_arr!(arr; ..);
+    assert_eq!(all, &arr);
+
+    let (empty,): (&[u16; 0],) = multindex_arr!(arr; 7..);
+    assert_eq!(empty, &[]);
+
+    {
+        let (first, mid, rest): (_, _, &mut [u16; 2]) = multindex_arr_mut!(arr; 0, 1..=4, ..);
+        *first += 100;
+        mid.reverse();
+        rest[1] = 0;
+    }
+    assert_eq!(arr, [103, 21, 13, 8, 5, 34, 0]);
+
+    {
+        let arr_ref = &mut arr;
+        let (a, b) = multindex_arr_mut!(arr_ref; 1, 5);
+        core::mem::swap(a, b);
+    }
+    assert_eq!(arr, [103, 34, 13, 8, 5, 21, 0]);
+}
+
+#[test]
+fn array_indexing_aliasing_shared() {
+    let arr = [3u16, 5, 8, 13, 21, 34, 55];
+
+    let (a, b, rest): (_, _, &[u16; 6]) = multindex_arr!(arr; 1..3, 2, 1..);
+    assert_eq!((a, b, rest), (&[5, 8], &8, &[5, 8, 13, 21, 34, 55]));
+}
+
+fn split_generic<T, const N: usize>(arr: &mut [T; N]) -> (&mut T, &mut [T; 2]) {
+    multindex_arr_mut!(arr; 0, 1..3)
+}
+
+#[test]
+fn array_indexing_generic() {
+    let mut arr = [3u16, 5, 8, 13];
+    let (a, b) = split_generic(&mut arr);
+    *a += b[0] + b[1];
+    assert_eq!(arr, [16, 5, 8, 13]);
+
+    let mut arr = ["foo", "bar", "baz"];
+    let (a, b) = split_generic(&mut arr);
+    assert_eq!((*a, *b), ("foo", ["bar", "baz"]));
+}
+
+#[test]
+fn array_indexing_exhaustive() {
+    let mut arr = [3u16, 5, 8, 13, 21, 34, 55];
+
+    assert_eq!(
+        multindex_arr_exhaustive_mut!(arr; 0, 1..3, ..=5, 6),
+        (&mut 3, &mut [5, 8], &mut [13, 21, 34], &mut 55),
+    );
+
+    let (first, rest): (_, &mut [u16; 6]) = multindex_arr_exhaustive_mut!(arr; 0, 1..);
+    *first = 0;
+    rest[5] = 1;
+    assert_eq!(arr, [0, 5, 8, 13, 21, 34, 1]);
+
+    assert_eq!(
+        multindex_arr_exhaustive_mut!(&mut arr; ..0, ..),
+        (&mut [], &mut [0, 5, 8, 13, 21, 34, 1]),
+    );
+
+    let mut empty: [u16; 0] = [];
+    assert_eq!(multindex_arr_exhaustive_mut!(empty;), ());
+    assert_eq!(multindex_arr_exhaustive_mut!(empty; ..), (&mut [],));
+}
+
+/// Records its id in a shared list when dropped.
+#[derive(Debug)]
+struct DropRecorder {
+    id: u8,
+    dropped: Rc<RefCell<Vec<u8>>>,
+}
+
+impl Drop for DropRecorder {
+    fn drop(&mut self) {
+        self.dropped.borrow_mut().push(self.id);
+    }
+}
+
+fn recorders<const N: usize>(dropped: &Rc<RefCell<Vec<u8>>>) -> [DropRecorder; N] {
+    let mut id = 0;
+    [(); N].map(|_| {
+        id += 1;
+        DropRecorder {
+            id: id - 1,
+            dropped: dropped.clone(),
+        }
+    })
+}
+
+fn ids<const N: usize>(arr: &[DropRecorder; N]) -> Vec<u8> {
+    arr.iter().map(|x| x.id).collect()
+}
+
+#[test]
+fn array_splitting() {
+    let arr = [3u16, 5, 8, 13, 21, 34, 55];
+
+    assert_eq!(multisplit!(arr; 2), (8,));
+    assert_eq!(multisplit!(arr; 2..4, 0), ([8, 13], 3));
+
+    let (first, mid, rest): (_, _, [u16; 3]) = multisplit!(arr; 0, 1..4, ..);
+    assert_eq!((first, mid, rest), (3, [5, 8, 13], [21, 34, 55]));
+
+    let (all,): ([u16; 7],) = multisplit!(arr; ..);
+    assert_eq!(all, arr);
+
+    let (empty,): ([u16; 0],) = multisplit!(arr; 7..);
+    assert_eq!(empty, []);
+
+    assert_eq!(multisplit!(arr;), ());
+}
+
+#[test]
+fn array_splitting_drops() {
+    let dropped = Rc::new(RefCell::new(Vec::new()));
+
+    let (a, b, c): (_, _, [DropRecorder; 2]) = multisplit!(recorders::<8>(&dropped); 5, 1..3, 6..);
+    // The elements that weren't moved out are dropped in order.
+    assert_eq!(*dropped.borrow(), [0, 3, 4]);
+    assert_eq!((a.id, ids(&b), ids(&c)), (5, vec![1, 2], vec![6, 7]));
+
+    drop((c, a));
+    assert_eq!(*dropped.borrow(), [0, 3, 4, 6, 7, 5]);
+    drop(b);
+
+    dropped.borrow_mut().clear();
+    multisplit!(recorders::<3>(&dropped););
+    assert_eq!(*dropped.borrow(), [0, 1, 2]);
+
+    dropped.borrow_mut().clear();
+    let (all,): ([DropRecorder; 3],) = multisplit!(recorders::<3>(&dropped); ..);
+    assert_eq!(*dropped.borrow(), []);
+    drop(all);
+    assert_eq!(*dropped.borrow(), [0, 1, 2]);
+}
@@ -0,0 +1,122 @@
+use multindex::{bitindex, bits::BitField};
+
+use core::cell::Cell;
+
+fn stop_unwind(func: impl FnOnce()) -> std::thread::Result<()> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    catch_unwind(AssertUnwindSafe(func))
+}
+
+#[test]
+fn bit_indexing_integers() {
+    let mut reg = 0xABCD_u16;
+    {
+        let (a, b, c, d) = bitindex!(reg; ..4, 4..8, 8..=11, 12..);
+        assert_eq!((a.get(), b.get(), c.get(), d.get()), (0xD, 0xC, 0xB, 0xA));
+        assert_eq!((a.start(), a.width()), (0, 4));
+        assert_eq!((d.start(), d.width()), (12, 4));
+    }
+    {
+        // The arguments don't need to be sorted.
+        let (mut high, mut low) = bitindex!(reg; 8..16, 0..8);
+        high.set(0x12);
+        // Bits that don't fit in the field are ignored.
+        low.set(0xF34);
+    }
+    assert_eq!(reg, 0x1234);
+
+    let mut wide = u128::MAX;
+    {
+        let (mut low, mut high) = bitindex!(wide; 0..64, 64..);
+        assert_eq!((low.get(), high.get()), (u64::MAX, u64::MAX));
+        low.set(0);
+        high.set(1);
+    }
+    assert_eq!(wide, 1 << 64);
+
+    let mut byte = 0u8;
+    {
+        let (mut bit0, mut bit7, empty) = bitindex!(byte; 0, 7, 8..);
+        bit0.set(1);
+        bit7.set(3);
+        assert_eq!(empty.width(), 0);
+        assert_eq!(empty.get(), 0);
+    }
+    assert_eq!(byte, 0b1000_0001);
+
+    // Temporaries are borrowed for the duration of the statement.
+    assert_eq!(bitindex!(0b1010_u8; 1..3).0.get(), 0b01);
+}
+
+#[test]
+fn bit_indexing_bytes() {
+    let mut bytes = vec![0u8; 4];
+    {
+        let (mut a, mut b, mut c) = bitindex!(bytes; 4..12, 12..30, 30..);
+        a.set(0xAB);
+        b.set(0x3_FFFF);
+        c.set(0b10);
+        assert_eq!((a.get(), b.get(), c.get()), (0xAB, 0x3_FFFF, 0b10));
+    }
+    assert_eq!(bytes, [0xB0, 0xFA, 0xFF, 0xBF]);
+
+    {
+        let (mut all,) = bitindex!(bytes[..]; ..);
+        assert_eq!(all.width(), 32);
+        assert_eq!(all.get(), 0xBFFF_FAB0);
+        all.set(0x1234_5678);
+    }
+    assert_eq!(bytes, [0x78, 0x56, 0x34, 0x12]);
+
+    let mut arr = [0u8; 9];
+    {
+        let (mut bit, mut field) = bitindex!(arr; 3, 4..68);
+        bit.set(1);
+        field.set(u64::MAX);
+    }
+    assert_eq!(arr, [0xF8, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F]);
+}
+
+#[test]
+fn bit_indexing_errors() {
+    let mut reg = 0u8;
+    assert!(stop_unwind(|| {
+        let _ = bitindex!(reg; 0..9);
+    })
+    .is_err());
+    assert!(stop_unwind(|| {
+        let _ = bitindex!(reg; 8);
+    })
+    .is_err());
+    assert!(stop_unwind(|| {
+        let _ = bitindex!(reg; 0..8);
+    })
+    .is_ok());
+
+    let mut bytes = [0u8; 9];
+    assert!(stop_unwind(|| {
+        let _ = bitindex!(bytes; 10..73);
+    })
+    .is_err());
+    // Fields can't be longer than 64 bits,
+    // bounded ranges are checked at compile-time, unbounded ones at runtime.
+    assert!(stop_unwind(|| {
+        let _ = bitindex!(bytes; 1, ..);
+    })
+    .is_err());
+    assert!(stop_unwind(|| {
+        let _ = bitindex!(bytes; 8, ..);
+    })
+    .is_ok());
+}
+
+#[test]
+fn bit_field_debug() {
+    let mut reg = 0b0110_0000u8;
+    let (field,) = bitindex!(reg; 5..7);
+    let _: &BitField<'_, Cell<u8>> = &field;
+    assert_eq!(
+        format!("{:?}", field),
+        "BitField { start: 5, width: 2, value: 3 }"
+    );
+}
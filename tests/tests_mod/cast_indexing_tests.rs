@@ -0,0 +1,81 @@
+use multindex::{endian::u16be, multiget, multiget_mut, multindex, multindex_mut, pod::Pod};
+
+fn stop_unwind(func: impl FnOnce()) -> std::thread::Result<()> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    catch_unwind(AssertUnwindSafe(func))
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Header {
+    magic: [u8; 2],
+    len: u16be,
+}
+
+unsafe impl Pod for Header {}
+
+#[repr(C, align(4))]
+struct Aligned([u8; 12]);
+
+#[test]
+fn cast_indexing() {
+    let mut bytes = [b'M', b'G', 0, 5, 1, 2, 3, 4, 5, 6];
+
+    let header = Header {
+        magic: *b"MG",
+        len: u16be::new(5),
+    };
+
+    assert_eq!(multindex!(bytes; cast<Header> @ 0..4), (&header,));
+    assert_eq!(
+        multindex!(bytes; cast<Header> @ ..4, .., cast<[u8; 2]> @ 8..10),
+        (&header, &[1, 2, 3, 4], &[5, 6]),
+    );
+    assert_eq!(
+        multiget!(bytes; cast<Header> @ 6..=9),
+        Some((&Header {
+            magic: [3, 4],
+            len: u16be::new(0x0506),
+        },)),
+    );
+    assert_eq!(multiget!(bytes; cast<Header> @ 7..11), None);
+
+    {
+        let (header, rest) = multindex_mut!(bytes; cast<Header> @ 0..4, ..);
+        header.len.set(rest.len() as u16);
+        header.magic.reverse();
+    }
+    assert_eq!(bytes[..4], [b'G', b'M', 0, 6]);
+
+    {
+        let (a, b) = multiget_mut!(bytes; cast<[u16be; 2]> @ 2..6, cast<u8> @ 9..10).unwrap();
+        a[1] = u16be::new(0xABCD);
+        *b = 0;
+    }
+    assert_eq!(bytes, [b'G', b'M', 0, 6, 0xAB, 0xCD, 3, 4, 5, 0]);
+}
+
+#[test]
+fn cast_indexing_alignment() {
+    let mut aligned = Aligned([0; 12]);
+    let bytes = &mut aligned.0;
+
+    {
+        let (a, b) = multindex_mut!(bytes; cast<u32> @ 0..4, cast<u32> @ 8..12);
+        *a = 0x0102_0304;
+        *b = u32::MAX;
+    }
+    assert_eq!(
+        multindex!(*bytes; cast<u32> @ 0..4, cast<u32> @ 8..12),
+        (&0x0102_0304, &u32::MAX),
+    );
+
+    assert!(stop_unwind(|| {
+        let _ = multindex!(*bytes; cast<u32> @ 1..5);
+    })
+    .is_err());
+    assert!(stop_unwind(|| {
+        let _ = multiget_mut!(bytes; cast<u16> @ 2..4, cast<u32> @ 6..10);
+    })
+    .is_err());
+}
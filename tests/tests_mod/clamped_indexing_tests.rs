@@ -0,0 +1,102 @@
+use multindex::{multiget_clamped, multiget_clamped_mut};
+
+#[test]
+fn clamped_indexing() {
+    let arr = [3u8, 5, 8, 13, 21, 34];
+
+    assert_eq!(
+        multiget_clamped!(arr; 0, 1..=2, 3..6),
+        Some((&3, &[5, 8][..], &[13, 21, 34][..])),
+    );
+    assert_eq!(
+        multiget_clamped!(arr[..4]; 0, 1..=2, 3..6),
+        Some((&3, &[5, 8][..], &[13][..])),
+    );
+    assert_eq!(
+        multiget_clamped!(arr[..2]; 0, 1..=2, 3..6),
+        Some((&3, &[5][..], &[][..])),
+    );
+    assert_eq!(multiget_clamped!(arr[..0]; 0, 1..=2, 3..6), None);
+
+    // Ranges that start past the end of the slice are empty.
+    assert_eq!(
+        multiget_clamped!(arr; 2..4, 10..20, 30..),
+        Some((&[8, 13][..], &[][..], &[][..])),
+    );
+
+    // The arguments don't need to be in order.
+    assert_eq!(
+        multiget_clamped!(arr[..3]; 4..6, 1, 2..4),
+        Some((&[][..], &5, &[8][..])),
+    );
+
+    // Indices must be in bounds.
+    assert_eq!(multiget_clamped!(arr; 0..10, 6), None);
+
+    // `multiget_clamped` allows overlapping arguments, like `multiget`.
+    assert_eq!(
+        multiget_clamped!(arr; 0..10, 3),
+        Some((&[3, 5, 8, 13, 21, 34][..], &13)),
+    );
+}
+
+#[test]
+fn clamped_indexing_unbounded() {
+    let arr = [3u8, 5, 8, 13, 21, 34];
+
+    assert_eq!(
+        multiget_clamped!(arr[..4]; ..2, ..),
+        Some((&[3, 5][..], &[8, 13][..])),
+    );
+    assert_eq!(
+        multiget_clamped!(arr[..1]; ..2, ..),
+        Some((&[3][..], &[][..])),
+    );
+
+    // A `..` followed by another argument is bounded by the start of that argument.
+    assert_eq!(
+        multiget_clamped!(arr[..3]; 1.., 5..),
+        Some((&[5, 8][..], &[][..])),
+    );
+}
+
+#[test]
+fn clamped_indexing_typed() {
+    let arr = [0u8, 3, 0, 5, 0, 8];
+
+    assert_eq!(
+        multiget_clamped!(arr[..4]; u16be @ 0..2, 2..6),
+        Some((3, &[0, 5][..])),
+    );
+
+    // Typed arguments must be in bounds.
+    assert_eq!(multiget_clamped!(arr[..4]; 0..2, u16be @ 4..6), None);
+}
+
+#[test]
+fn clamped_indexing_mut() {
+    let mut arr = [3u8, 5, 8, 13, 21, 34];
+
+    {
+        let (a, b, c) = multiget_clamped_mut!(arr[..4]; 0, 1..=2, 3..6).unwrap();
+        *a = 100;
+        b.reverse();
+        c.iter_mut().for_each(|x| *x = 0);
+    }
+    assert_eq!(arr, [100, 8, 5, 0, 21, 34]);
+
+    {
+        let (a, b) = multiget_clamped_mut!(arr; 4..8, 0..4).unwrap();
+        a.copy_from_slice(&[1, 2]);
+        b.iter_mut().for_each(|x| *x = 7);
+    }
+    assert_eq!(arr, [7, 7, 7, 7, 1, 2]);
+
+    let mut vec = arr.to_vec();
+    vec.truncate(2);
+    assert_eq!(multiget_clamped_mut!(vec; 1, 2), None);
+    assert_eq!(
+        multiget_clamped_mut!(vec; 1, 2..),
+        Some((&mut 7, &mut [][..])),
+    );
+}
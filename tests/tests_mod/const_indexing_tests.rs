@@ -0,0 +1,39 @@
+use multindex::multindex_const;
+
+const FIBS: [u16; 7] = [3, 5, 8, 13, 21, 34, 55];
+
+const fn split_generic<T>(slice: &[T]) -> (&T, &[T; 2], &[T]) {
+    multindex_const!(slice; 0, 1..3, ..)
+}
+
+#[test]
+fn const_indexing() {
+    const A: (&u16,) = multindex_const!(&FIBS; 2);
+    assert_eq!(A, (&8,));
+
+    const B: (&u16, &[u16; 3], &u16, &[u16]) = multindex_const!(&FIBS; 0, 1..=3, 4, ..);
+    assert_eq!(B, (&3, &[5, 8, 13], &21, &[34, 55][..]));
+
+    const C: (&[u16; 5], &[u16]) = multindex_const!(&FIBS; .., 5..);
+    assert_eq!(C, (&[3, 5, 8, 13, 21], &[34, 55][..]));
+
+    static D: (&u16, &[u16; 2], &[u16]) = split_generic(&FIBS);
+    assert_eq!(D, (&3, &[5, 8], &[13, 21, 34, 55][..]));
+
+    const E: (&[u16; 7], &[u16]) = multindex_const!(&FIBS; ..7, ..);
+    assert_eq!(E, (&FIBS, &[][..]));
+
+    // Aliasing is allowed, since this only returns shared references.
+    const F: (&u16, &[u16; 3], &u16) = multindex_const!(&FIBS; 2, 1..4, 2);
+    assert_eq!(F, (&8, &[5, 8, 13], &8));
+
+    let strs = ["foo", "bar", "baz"];
+    assert_eq!(split_generic(&strs), (&"foo", &["bar", "baz"], &[][..]));
+}
+
+#[test]
+#[should_panic]
+fn const_indexing_out_of_bounds_at_runtime() {
+    let slice = &[3u8, 5, 8][..];
+    let _ = split_generic(&slice[..2]);
+}
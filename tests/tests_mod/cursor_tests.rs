@@ -0,0 +1,92 @@
+use multindex::{peek, take, Cursor};
+
+#[test]
+fn cursor_take() {
+    let arr = [3u16, 5, 8, 13, 21, 34, 55, 89];
+    let mut cursor = Cursor::new(&arr[..]);
+
+    // Advances past the furthest argument, regardless of their order.
+    assert_eq!(take!(cursor; 2, 0), Some((&8, &3)));
+    assert_eq!((cursor.position(), cursor.len()), (3, 5));
+    assert_eq!(cursor.rest(), &[13, 21, 34, 55, 89]);
+
+    // Shared indexing allows overlap.
+    assert_eq!(take!(cursor; 0..2, 1), Some((&[13, 21], &21)));
+    assert_eq!(cursor.position(), 5);
+
+    // Out of bounds arguments leave the cursor untouched.
+    assert_eq!(take!(cursor; 0, 3), None);
+    assert_eq!(take!(cursor; 3..4), None);
+    assert_eq!(cursor.position(), 5);
+
+    // `dyn` arguments are relative to the cursor too.
+    let one = 1;
+    assert_eq!(take!(cursor; dyn one), Some((&55,)));
+    assert_eq!(cursor.position(), 7);
+
+    let two = 2;
+    assert_eq!(take!(cursor; dyn ..two), None);
+    assert_eq!(take!(cursor; dyn ..one), Some((&[89][..],)));
+    assert!(cursor.is_empty());
+
+    // Empty ranges don't advance the cursor
+    assert_eq!(take!(cursor; ..0), Some((&[],)));
+    assert_eq!(take!(cursor; ..), Some((&[][..],)));
+    assert_eq!(cursor.position(), 8);
+}
+
+#[test]
+fn cursor_take_rest() {
+    let arr = [3u16, 5, 8, 13];
+
+    let mut cursor = Cursor::new(&arr[..]);
+    assert_eq!(take!(cursor; 1, ..), Some((&5, &[8, 13][..])));
+    assert!(cursor.is_empty());
+    assert_eq!(cursor.position(), 4);
+
+    let mut cursor = Cursor::new(&arr[..]);
+    let two = 2;
+    assert_eq!(take!(cursor; 0, dyn two..), Some((&3, &[8, 13][..])));
+    assert!(cursor.is_empty());
+}
+
+#[test]
+fn cursor_typed_args() {
+    let bytes = [0u8, 0, 1, 10, 20, 0x80, 0x3F, 7];
+    let mut cursor = Cursor::new(&bytes[..]);
+
+    // Taking through a mutable reference to a cursor.
+    fn grab_u32(cursor: &mut Cursor<'_, u8>) -> Option<u32> {
+        take!(*cursor; u32be @ ..4).map(|(x,)| x)
+    }
+
+    assert_eq!(grab_u32(&mut cursor), Some(266));
+    assert_eq!(
+        take!(cursor; 0, cast<[u8; 2]> @ 1..3),
+        Some((&20, &[0x80, 0x3F]))
+    );
+    assert_eq!(grab_u32(&mut cursor), None);
+    assert_eq!(cursor.position(), 7);
+}
+
+#[test]
+fn cursor_peek_skip() {
+    let arr = [3u16, 5, 8, 13, 21];
+    let mut cursor = Cursor::new(&arr[..]);
+
+    assert_eq!(peek!(cursor; 0, 1..3), Some((&3, &[5, 8])));
+    assert_eq!(peek!(cursor; 5), None);
+    assert_eq!(cursor.position(), 0);
+
+    assert_eq!(cursor.skip(2), Some(&[3, 5][..]));
+    assert_eq!(peek!(&cursor; 0, ..), Some((&8, &[13, 21][..])));
+    assert_eq!(cursor.skip(4), None);
+    assert_eq!(cursor.skip(3), Some(&[8, 13, 21][..]));
+    assert_eq!(cursor.skip(0), Some(&[][..]));
+    assert_eq!(cursor.position(), 5);
+
+    let copy = cursor;
+    assert_eq!(copy.position(), cursor.position());
+    assert_eq!(format!("{:?}", copy), "Cursor { position: 5, rest: [] }");
+    assert!(Cursor::<u8>::default().is_empty());
+}
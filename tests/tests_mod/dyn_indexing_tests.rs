@@ -0,0 +1,102 @@
+use multindex::{
+    multiget_dyn, multiget_dyn_mut, multindex_dyn, multindex_dyn_mut, Error, OutOfBounds,
+};
+
+#[test]
+fn dyn_indexing() {
+    let mut arr = [3u16, 5, 8, 13, 21, 34, 55];
+    let (zero, two, four) = (0, 2, 4);
+
+    assert_eq!(multindex_dyn!(arr; two), Ok((&8,)));
+    assert_eq!(multindex_dyn!(arr; two..four), Ok((&[8, 13][..],)));
+    assert_eq!(multindex_dyn!(arr; four..), Ok((&[21, 34, 55][..],)));
+    assert_eq!(multindex_dyn!(arr; ..two), Ok((&[3, 5][..],)));
+    assert_eq!(multindex_dyn!(arr; two..=four), Ok((&[8, 13, 21][..],)));
+    assert_eq!(multindex_dyn!(arr; ..=two), Ok((&[3, 5, 8][..],)));
+    assert_eq!(
+        multindex_dyn!(arr; zero, .., four, ..),
+        Ok((&3, &[5, 8, 13][..], &21, &[34, 55][..]))
+    );
+
+    assert_eq!(
+        multindex_dyn_mut!(arr; zero, .., four, ..),
+        Ok((&mut 3, &mut [5, 8, 13][..], &mut 21, &mut [34, 55][..]))
+    );
+    assert_eq!(
+        multiget_dyn!(arr; zero, .., four, ..),
+        Some((&3, &[5, 8, 13][..], &21, &[34, 55][..]))
+    );
+    assert_eq!(
+        multiget_dyn_mut!(arr; zero, .., four, ..),
+        Some((&mut 3, &mut [5, 8, 13][..], &mut 21, &mut [34, 55][..]))
+    );
+
+    {
+        let slice = &mut arr[..];
+        let (a, b) = multindex_dyn_mut!(slice; slice.len() - 1, zero..two).unwrap();
+        *a += b[0] + b[1];
+    }
+    assert_eq!(arr, [3, 5, 8, 13, 21, 34, 63]);
+
+    assert_eq!(multindex_dyn!(arr;), Ok(()));
+    assert_eq!(multindex_dyn_mut!(arr;), Ok(()));
+}
+
+#[test]
+fn dyn_indexing_aliasing_shared() {
+    let arr = [3u16, 5, 8, 13, 21, 34, 55];
+    let (one, three) = (1, 3);
+
+    assert_eq!(
+        multindex_dyn!(arr; one, three, one..three + 1),
+        Ok((&5, &13, &[5, 8, 13][..]))
+    );
+    assert_eq!(
+        multiget_dyn!(arr; one, three, one..three + 1),
+        Some((&5, &13, &[5, 8, 13][..]))
+    );
+}
+
+#[test]
+fn dyn_indexing_errors() {
+    let mut arr = [3u16, 5, 8, 13, 21, 34, 55];
+    let (one, three, seven) = (1, 3, 7);
+
+    assert_eq!(
+        multindex_dyn_mut!(arr; one, three, one..three),
+        Err(Error::OverlappingIndexArgs { left: 0, right: 2 })
+    );
+    assert_eq!(multiget_dyn_mut!(arr; one, three, one..three), None);
+
+    assert_eq!(
+        multindex_dyn!(arr; one, seven),
+        Err(Error::OutOfBounds(OutOfBounds {
+            slice_len: 7,
+            max_bounded_end: 8,
+            argument: 1,
+        }))
+    );
+    assert_eq!(
+        multindex_dyn_mut!(arr; ..three, seven..),
+        Ok((&mut [3, 5, 8][..], &mut [][..]))
+    );
+    assert_eq!(multiget_dyn!(arr; one, seven), None);
+    assert_eq!(multiget_dyn_mut!(arr; one..seven + 1), None);
+
+    assert_eq!(
+        multindex_dyn!(arr; one.., ..three),
+        Err(Error::NextStartIsUnbounded { current_index: 0 })
+    );
+    assert_eq!(
+        multindex_dyn!(arr; three.., one),
+        Err(Error::NextStartIsLessThanCurrent { current_index: 0 })
+    );
+    assert_eq!(
+        multindex_dyn!(arr; one..=usize::MAX),
+        Err(Error::InclusiveUptoUsizeMax { current_index: 0 })
+    );
+    assert_eq!(
+        multindex_dyn!(arr; one, usize::MAX),
+        Err(Error::InclusiveUptoUsizeMax { current_index: 1 })
+    );
+}
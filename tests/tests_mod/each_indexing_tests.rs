@@ -0,0 +1,99 @@
+use multindex::{multiget_each, multiget_each_mut};
+
+#[test]
+fn each_indexing() {
+    let arr = [3u8, 5, 8, 13, 21, 34];
+
+    assert_eq!(
+        multiget_each!(arr; 0, 1..=2, 3..6),
+        (Some(&3), Some(&[5, 8]), Some(&[13, 21, 34])),
+    );
+    assert_eq!(
+        multiget_each!(arr[..4]; 0, 1..=2, 3..6),
+        (Some(&3), Some(&[5, 8]), None),
+    );
+    assert_eq!(
+        multiget_each!(arr[..2]; 0, 1..=2, 3..6),
+        (Some(&3), None, None),
+    );
+    assert_eq!(multiget_each!(arr[..0]; 0, 1..=2, 3..6), (None, None, None));
+
+    // Arguments that end exactly at the end of the slice are in bounds.
+    assert_eq!(
+        multiget_each!(arr; 5, 6, 4..6),
+        (Some(&34), None, Some(&[21, 34]))
+    );
+
+    // The arguments don't need to be in order.
+    assert_eq!(
+        multiget_each!(arr[..3]; 4..6, 1, 0..3),
+        (None, Some(&5), Some(&[3, 5, 8])),
+    );
+
+    // `multiget_each` allows overlapping arguments, like `multiget`.
+    assert_eq!(
+        multiget_each!(arr; 0..4, 3),
+        (Some(&[3, 5, 8, 13]), Some(&13))
+    );
+}
+
+#[test]
+fn each_indexing_unbounded() {
+    let arr = [3u8, 5, 8, 13, 21, 34];
+
+    // A trailing unbounded range is in bounds if it starts at or before the end of the slice.
+    assert_eq!(
+        multiget_each!(arr[..4]; ..2, ..),
+        (Some(&[3, 5]), Some(&[8, 13][..])),
+    );
+    assert_eq!(
+        multiget_each!(arr[..2]; ..2, ..),
+        (Some(&[3, 5]), Some(&[][..])),
+    );
+    assert_eq!(multiget_each!(arr[..1]; ..2, ..), (None, None));
+    assert_eq!(multiget_each!(arr; 1, 7..), (Some(&5), None));
+
+    // A `..` followed by another argument is bounded.
+    assert_eq!(multiget_each!(arr[..4]; 1.., 5), (None, None),);
+    assert_eq!(
+        multiget_each!(arr; 1.., 5),
+        (Some(&[5, 8, 13, 21]), Some(&34)),
+    );
+}
+
+#[test]
+fn each_indexing_typed() {
+    let arr = [0u8, 3, 0, 5, 0, 8];
+
+    assert_eq!(
+        multiget_each!(arr[..4]; u16be @ 0..2, u16be @ 2..4, u16be @ 4..6),
+        (Some(3), Some(5), None),
+    );
+}
+
+#[test]
+fn each_indexing_mut() {
+    let mut arr = [3u8, 5, 8, 13, 21, 34];
+
+    {
+        let (a, b, c) = multiget_each_mut!(arr[..4]; 0, 1..=2, 3..6);
+        assert_eq!(c, None);
+        *a.unwrap() = 100;
+        b.unwrap().reverse();
+    }
+    assert_eq!(arr, [100, 8, 5, 13, 21, 34]);
+
+    {
+        let (a, b) = multiget_each_mut!(arr; 3..5, ..);
+        a.unwrap().copy_from_slice(&[1, 2]);
+        b.unwrap().iter_mut().for_each(|x| *x = 0);
+    }
+    assert_eq!(arr, [100, 8, 5, 1, 2, 0]);
+
+    let mut vec = arr.to_vec();
+    vec.truncate(2);
+    assert_eq!(
+        multiget_each_mut!(vec; 1, 2, 0),
+        (Some(&mut 8), None, Some(&mut 100)),
+    );
+}
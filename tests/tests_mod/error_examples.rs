@@ -96,3 +96,363 @@ fn overlapping_index_args_error() {
     err_case(&prenorm_from![1, .., 10, 5], Idxs { l: 1, r: 3 });
     err_case(&prenorm_from![1, 11, 10, ..], Idxs { l: 1, r: 3 });
 }
+
+#[test]
+fn typed_argument_length_error() {
+    fn check(prenorm: &[PrenormIndex], type_sizes: &[Option<usize>]) -> Result<(), Error> {
+        let IAAS { ind_args, .. } = IndexArgument::many_from_prenorm(prenorm).unwrap();
+        IndexArgument::check_type_sizes(&ind_args, type_sizes)
+    }
+
+    assert_eq!(check(&prenorm_from![0, 1..5], &[None, Some(4)]), Ok(()));
+    assert_eq!(check(&prenorm_from![0, 1..=4], &[None, Some(4)]), Ok(()));
+    assert_eq!(check(&prenorm_from![..2, ..6], &[Some(2), Some(4)]), Ok(()));
+
+    let err = |current_index, type_size| {
+        Err(Error::TypedArgumentLength {
+            current_index,
+            type_size,
+        })
+    };
+    assert_eq!(check(&prenorm_from![0, 1..4], &[None, Some(4)]), err(1, 4));
+    assert_eq!(
+        check(&prenorm_from![0..2, 3], &[Some(2), Some(1)]),
+        err(1, 1)
+    );
+    assert_eq!(check(&prenorm_from![2.., 5], &[Some(2), None]), err(0, 2));
+    assert_eq!(check(&prenorm_from![0, 2..], &[None, Some(8)]), err(1, 8));
+}
+
+#[test]
+fn mismatched_lengths_error() {
+    fn check(prenorm: &[PrenormIndex], pairs: &[[u16; 2]]) -> Result<(), Error> {
+        let IAAS { ind_args, .. } = IndexArgument::many_from_prenorm(prenorm).unwrap();
+        IndexArgument::check_same_lengths(&ind_args, pairs)
+    }
+
+    assert_eq!(check(&prenorm_from![0..4, 8..12], &[[0, 1]]), Ok(()));
+    assert_eq!(
+        check(&prenorm_from![..2, 5, 2..=3, 6], &[[0, 2], [1, 3]]),
+        Ok(())
+    );
+    assert_eq!(check(&prenorm_from![1.., 8..], &[]), Ok(()));
+
+    let err = |left, right| Err(Error::MismatchedLengths { left, right });
+    assert_eq!(check(&prenorm_from![0..4, 8..11], &[[0, 1]]), err(0, 1));
+    assert_eq!(check(&prenorm_from![0, 2..3], &[[0, 1]]), err(0, 1));
+    assert_eq!(
+        check(&prenorm_from![0..2, 3, 6, 8..], &[[1, 2], [0, 3]]),
+        err(0, 3)
+    );
+    assert_eq!(check(&prenorm_from![4..8, 0..], &[[0, 1]]), err(0, 1));
+}
+
+#[test]
+fn not_a_permutation_error() {
+    fn check(prenorm: &[PrenormIndex]) -> Result<(), Error> {
+        let IAAS { ind_args, .. } = IndexArgument::many_from_prenorm(prenorm).unwrap();
+        IndexArgument::check_permutation(&ind_args)
+    }
+
+    assert_eq!(check(&prenorm_from![]), Ok(()));
+    assert_eq!(check(&prenorm_from![0]), Ok(()));
+    assert_eq!(check(&prenorm_from![2, 0, 1]), Ok(()));
+
+    let err = |current_index, len| Err(Error::NotAPermutation { current_index, len });
+    assert_eq!(check(&prenorm_from![1]), err(0, 1));
+    assert_eq!(check(&prenorm_from![0, 3, 1]), err(1, 3));
+    assert_eq!(check(&prenorm_from![0, 1..2]), err(1, 2));
+    assert_eq!(check(&prenorm_from![0, 1..]), err(1, 2));
+}
+
+#[test]
+fn only_mut_overlapping_error() {
+    fn check(prenorm: &[PrenormIndex], is_mut: &'static [bool]) -> Result<(), Error> {
+        let IAAS { stats, ind_args } = IndexArgument::many_from_prenorm(prenorm).unwrap();
+        let expected = AreAllDisjoint::OnlyMut(is_mut);
+        IndexProperties::new(&ind_args, &stats, expected)
+            .are_disjoint
+            .check_is_expected(&expected)
+    }
+
+    let err = |left, right| Err(Error::OverlappingIndexArgs { left, right });
+
+    assert_eq!(check(&prenorm_from![0..4, 2], &[false, false]), Ok(()));
+    assert_eq!(check(&prenorm_from![0..4, 2], &[true, false]), err(0, 1));
+    assert_eq!(check(&prenorm_from![0..4, 2], &[false, true]), err(0, 1));
+    assert_eq!(check(&prenorm_from![0..4, 2], &[true, true]), err(0, 1));
+
+    assert_eq!(
+        check(
+            &prenorm_from![0..4, 2, 3..6, 8],
+            &[false, false, false, true]
+        ),
+        Ok(()),
+    );
+    assert_eq!(
+        check(
+            &prenorm_from![0..4, 2, 3..6, 8],
+            &[true, false, false, true]
+        ),
+        err(0, 1),
+    );
+    assert_eq!(
+        check(
+            &prenorm_from![0..4, 2, 3..6, 8],
+            &[false, false, true, false]
+        ),
+        err(0, 2),
+    );
+    assert_eq!(
+        check(
+            &prenorm_from![5, 0..4, 2, 5..7],
+            &[false, true, false, false]
+        ),
+        err(1, 2),
+    );
+}
+
+#[test]
+fn gap_before_argument_error() {
+    fn check(prenorm: &[PrenormIndex]) -> Result<(), Error> {
+        let IAAS { ind_args, .. } = IndexArgument::many_from_prenorm(prenorm).unwrap();
+        IndexArgument::check_exhaustive(prenorm, &ind_args)
+    }
+
+    assert_eq!(check(&prenorm_from![]), Ok(()));
+    assert_eq!(check(&prenorm_from![0]), Ok(()));
+    assert_eq!(check(&prenorm_from![0, 1..4, ..6, 6]), Ok(()));
+    assert_eq!(check(&prenorm_from![..0, 0..0, 0, 1..]), Ok(()));
+
+    let err = |current_index, expected_start| {
+        Err(Error::GapBeforeArgument {
+            current_index,
+            expected_start,
+        })
+    };
+    assert_eq!(check(&prenorm_from![1]), err(0, 0));
+    assert_eq!(check(&prenorm_from![0, 1..4, 5]), err(2, 4));
+    assert_eq!(check(&prenorm_from![0, 1..4, 5..]), err(2, 4));
+    assert_eq!(check(&prenorm_from![2..4, 0..2]), err(0, 0));
+    assert_eq!(check(&prenorm_from![0..2, 4..6, 2..4]), err(1, 2));
+}
+
+#[test]
+fn runtime_argument_error() {
+    use PrenormIndex::{Index, Runtime};
+
+    assert_eq!(IndexArgument::check_no_runtime(&[]), Ok(()));
+    assert_eq!(
+        IndexArgument::check_no_runtime(&prenorm_from![0, 1..]),
+        Ok(())
+    );
+
+    let err = |current_index| Err(Error::RuntimeArgument { current_index });
+    assert_eq!(IndexArgument::check_no_runtime(&[Runtime]), err(0));
+    assert_eq!(
+        IndexArgument::check_no_runtime(&[Index(0), Runtime]),
+        err(1)
+    );
+    assert_eq!(
+        IndexArgument::check_exhaustive(&[Index(0), Runtime], &[IndexArgument::EMPTY; 2]),
+        err(1),
+    );
+
+    let from_end = [Index(0), PrenormIndex::IndexFromEnd(1)];
+    assert_eq!(IndexArgument::check_no_dyn(&from_end), Ok(()));
+    assert_eq!(IndexArgument::check_no_runtime(&from_end), err(1));
+    assert_eq!(IndexArgument::check_no_dyn(&[Index(0), Runtime]), err(1));
+}
+
+#[test]
+fn overlapping_from_end_error() {
+    use PrenormIndex::{Index, IndexFromEnd, Range, RangeFromEnd, Runtime};
+
+    fn check(prenorm: &[PrenormIndex], expected: AreAllDisjoint) -> Result<(), Error> {
+        let IAAS { ind_args, .. } = IndexArgument::many_from_prenorm(prenorm).unwrap();
+        IndexArgument::check_from_end_disjoint(prenorm, &ind_args, &expected)
+    }
+    let yes = AreAllDisjoint::YES;
+    let unbounded = |start| Range {
+        start: Some(start),
+        end: None,
+    };
+
+    assert_eq!(check(&[], yes), Ok(()));
+    assert_eq!(check(&[Index(0), IndexFromEnd(1), Runtime], yes), Ok(()));
+    assert_eq!(
+        check(&[RangeFromEnd { start: 4, end: 2 }, IndexFromEnd(2)], yes),
+        Ok(())
+    );
+    assert_eq!(check(&[unbounded(5), IndexFromEnd(2)], yes), Ok(()));
+    assert_eq!(
+        check(&[unbounded(0), IndexFromEnd(4), IndexFromEnd(2)], yes),
+        Ok(())
+    );
+    assert_eq!(
+        check(&[IndexFromEnd(1), IndexFromEnd(1)], AreAllDisjoint::NO),
+        Ok(())
+    );
+
+    let err = |left, right| Err(Error::OverlappingIndexArgs { left, right });
+    assert_eq!(check(&[IndexFromEnd(1), IndexFromEnd(1)], yes), err(0, 1));
+    assert_eq!(
+        check(
+            &[Index(0), RangeFromEnd { start: 4, end: 0 }, IndexFromEnd(1)],
+            yes
+        ),
+        err(1, 2)
+    );
+    assert_eq!(
+        check(&[RangeFromEnd { start: 4, end: 2 }, IndexFromEnd(3)], yes),
+        err(0, 1)
+    );
+    assert_eq!(check(&[IndexFromEnd(2), unbounded(5)], yes), err(0, 1));
+    assert_eq!(
+        check(&[unbounded(0), IndexFromEnd(2), IndexFromEnd(4)], yes),
+        err(0, 2)
+    );
+}
+
+#[test]
+fn field_too_wide_error() {
+    fn check(prenorm: &[PrenormIndex]) -> Result<(), Error> {
+        let IAAS { ind_args, .. } = IndexArgument::many_from_prenorm(prenorm).unwrap();
+        IndexArgument::check_field_widths(&ind_args)
+    }
+
+    assert_eq!(check(&prenorm_from![]), Ok(()));
+    assert_eq!(check(&prenorm_from![0..64, 64..128]), Ok(()));
+    assert_eq!(check(&prenorm_from![0, 100..]), Ok(()));
+
+    let err = |current_index, width| {
+        Err(Error::FieldTooWide {
+            current_index,
+            width,
+        })
+    };
+    assert_eq!(check(&prenorm_from![0..65]), err(0, 65));
+    assert_eq!(check(&prenorm_from![0, 2..=80, 81..]), err(1, 79));
+    assert_eq!(check(&prenorm_from![0, 4.., 100]), err(1, 96));
+}
+
+#[cfg(feature = "rust_1_57")]
+#[test]
+fn panic_messages() {
+    fn panic_message(prenorm: &[PrenormIndex], expected: AreAllDisjoint) -> String {
+        let (err, ind_args) = match IndexArgument::many_from_prenorm(prenorm) {
+            Ok(iaas) => {
+                let props = IndexProperties::new(&iaas.ind_args, &iaas.stats, expected);
+                let err = props.are_disjoint.check_is_expected(&expected).unwrap_err();
+                (err, iaas.ind_args)
+            }
+            Err(e) => (e, vec![]),
+        };
+        let payload = std::panic::catch_unwind(|| err.panic(prenorm, &ind_args)).unwrap_err();
+        *payload.downcast::<String>().unwrap()
+    }
+
+    let cases: Vec<(Vec<PrenormIndex>, &str)> =
+        vec![
+        (
+            prenorm_from![0, 1.., ..10].to_vec(),
+            "argument 1 (`1..`) has an unbounded end, and the next argument (`..10`) \
+             has an unbounded start",
+        ),
+        (
+            vec![PrenormIndex::Runtime, PrenormIndex::Range { start: None, end: Some(3) }],
+            "argument 1 (`..3`) has an unbounded start, and the previous argument (`dyn _`) \
+             has an unbounded end",
+        ),
+        (
+            prenorm_from![0, 2, 10.., 9..].to_vec(),
+            "argument 2 (`10..`) has an unbounded end, and the next argument (`9..`) \
+             starts before it",
+        ),
+        (
+            prenorm_from![0, 3..=usize::MAX].to_vec(),
+            "argument 1 (`3..=usize::MAX`) ends at usize::MAX inclusive, which is not supported",
+        ),
+        (
+            prenorm_from![0, 4..10, 20, 6].to_vec(),
+            "argument 1 (`4..10`, covering 4..10) overlaps with argument 3 (`6`, covering 6..7)",
+        ),
+        (
+            prenorm_from![..8, 7, 15..].to_vec(),
+            "argument 0 (`..8`, covering 0..8) overlaps with argument 1 (`7`, covering 7..8)",
+        ),
+    ];
+
+    for (prenorm, expected) in cases {
+        assert_eq!(
+            panic_message(&prenorm, AreAllDisjoint::YES),
+            format!("multindex error: {}", expected),
+        );
+    }
+    {
+        let prenorm = prenorm_from![0, 1..4];
+        let IAAS { ind_args, .. } = IndexArgument::many_from_prenorm(&prenorm).unwrap();
+        let err = IndexArgument::check_type_sizes(&ind_args, &[None, Some(4)]).unwrap_err();
+        let payload = std::panic::catch_unwind(|| err.panic(&prenorm, &ind_args)).unwrap_err();
+        assert_eq!(
+            *payload.downcast::<String>().unwrap(),
+            "multindex error: argument 1 (`1..4`, covering 1..4) is a typed argument, \
+             which must be a range of 4 elements",
+        );
+    }
+    {
+        let prenorm = prenorm_from![0..4, 5, 8..11];
+        let IAAS { ind_args, .. } = IndexArgument::many_from_prenorm(&prenorm).unwrap();
+        let err = IndexArgument::check_same_lengths(&ind_args, &[[0, 2]]).unwrap_err();
+        let payload = std::panic::catch_unwind(|| err.panic(&prenorm, &ind_args)).unwrap_err();
+        assert_eq!(
+            *payload.downcast::<String>().unwrap(),
+            "multindex error: argument 0 (`0..4`, covering 0..4) and argument 2 \
+             (`8..11`, covering 8..11) must both be indices, or bounded ranges of the same length",
+        );
+    }
+    {
+        let prenorm = prenorm_from![0, 4, 1, 2];
+        let IAAS { ind_args, .. } = IndexArgument::many_from_prenorm(&prenorm).unwrap();
+        let err = IndexArgument::check_permutation(&ind_args).unwrap_err();
+        let payload = std::panic::catch_unwind(|| err.panic(&prenorm, &ind_args)).unwrap_err();
+        assert_eq!(
+            *payload.downcast::<String>().unwrap(),
+            "multindex error: argument 1 (`4`) must be an index less than 4, \
+             the amount of arguments, for them to be a permutation",
+        );
+    }
+    {
+        let prenorm = prenorm_from![0, 1..4, 5..8];
+        let IAAS { ind_args, .. } = IndexArgument::many_from_prenorm(&prenorm).unwrap();
+        let err = IndexArgument::check_exhaustive(&prenorm, &ind_args).unwrap_err();
+        let payload = std::panic::catch_unwind(|| err.panic(&prenorm, &ind_args)).unwrap_err();
+        assert_eq!(
+            *payload.downcast::<String>().unwrap(),
+            "multindex error: argument 2 (`5..8`, covering 5..8) must start at 4, \
+             leaving no gap before it",
+        );
+    }
+    {
+        let prenorm = [PrenormIndex::Index(0), PrenormIndex::Runtime];
+        let IAAS { ind_args, .. } = IndexArgument::many_from_prenorm(&prenorm).unwrap();
+        let err = IndexArgument::check_no_runtime(&prenorm).unwrap_err();
+        let payload = std::panic::catch_unwind(|| err.panic(&prenorm, &ind_args)).unwrap_err();
+        assert_eq!(
+            *payload.downcast::<String>().unwrap(),
+            "multindex error: argument 1 (`dyn _`) is a runtime argument, \
+             which this macro doesn't take",
+        );
+    }
+    {
+        let prenorm = prenorm_from![0, 1..81];
+        let IAAS { ind_args, .. } = IndexArgument::many_from_prenorm(&prenorm).unwrap();
+        let err = IndexArgument::check_field_widths(&ind_args).unwrap_err();
+        let payload = std::panic::catch_unwind(|| err.panic(&prenorm, &ind_args)).unwrap_err();
+        assert_eq!(
+            *payload.downcast::<String>().unwrap(),
+            "multindex error: argument 1 (`1..81`) is 80 bits long, \
+             bit fields can't be longer than 64 bits",
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use multindex::multindex_exhaustive_mut;
+
+#[test]
+fn exhaustive_indexing() {
+    let mut arr = [3u8, 5, 8, 13, 21, 34, 55, 89];
+
+    assert_eq!(multindex_exhaustive_mut!(arr; 0), (&mut 3,));
+    assert_eq!(
+        multindex_exhaustive_mut!(arr; 0, 1..3, ..=5, 6),
+        (&mut 3, &mut [5, 8], &mut [13, 21, 34], &mut 55),
+    );
+    assert_eq!(
+        multindex_exhaustive_mut!(arr; ..2, 2.., 4..),
+        (&mut [3, 5], &mut [8, 13], &mut [21, 34, 55, 89][..]),
+    );
+    assert_eq!(
+        multindex_exhaustive_mut!(arr; 0..0, 0, ..),
+        (&mut [], &mut 3, &mut [5, 8, 13, 21, 34, 55, 89][..]),
+    );
+}
+
+#[test]
+fn exhaustive_indexing_typed() {
+    let mut arr = [1u8, 2, 0, 0, 0, 10, 20];
+
+    let (tag, len, body) = multindex_exhaustive_mut!(arr; 0, u32le @ 1..5, ..);
+    *tag += 1;
+    len.set(len.get() + 256);
+    body.reverse();
+
+    assert_eq!(arr, [2, 2, 1, 0, 0, 20, 10]);
+}
+
+#[test]
+#[should_panic]
+fn exhaustive_indexing_out_of_bounds() {
+    let mut arr = [3u8, 5, 8];
+
+    let _ = multindex_exhaustive_mut!(arr; 0, 1..4);
+}
@@ -0,0 +1,143 @@
+use multindex::{
+    multiget, multiget_mut, multindex, multindex_mut, try_multindex, try_multindex_mut, OutOfBounds,
+};
+
+const TRAILER_LEN: usize = 4;
+
+#[test]
+fn from_end_indexing() {
+    let arr = [3u8, 5, 8, 13, 21, 34, 55, 89];
+
+    assert_eq!(multindex!(arr; end - 1), (&89,));
+    assert_eq!(multindex!(arr; end - 3..), (&[34, 55, 89],));
+    assert_eq!(multindex!(arr; end - 3..end), (&[34, 55, 89],));
+    assert_eq!(multindex!(arr; end - 3..end - 1), (&[34, 55],));
+    assert_eq!(multindex!(arr; end - TRAILER_LEN..), (&[21, 34, 55, 89],));
+    assert_eq!(
+        multindex!(arr; end - (TRAILER_LEN + 1)..end - TRAILER_LEN),
+        (&[13],)
+    );
+
+    // The arguments relative to the end can come in any order
+    assert_eq!(
+        multindex!(arr; 0, end - 1, end - 4..end - 2),
+        (&3, &89, &[21, 34]),
+    );
+
+    // `multindex` allows overlapping arguments
+    assert_eq!(multindex!(arr; end - 2.., end - 1), (&[55, 89], &89));
+}
+
+#[test]
+fn from_end_indexing_middle_range() {
+    let mut arr = [3u8, 5, 8, 13, 21, 34, 55, 89];
+
+    assert_eq!(
+        multindex!(arr; ..2, .., end - 2..),
+        (&[3, 5], &[8, 13, 21, 34][..], &[55, 89]),
+    );
+    assert_eq!(
+        multindex!(arr; 1, 3.., end - 3..end - 1, end - 1),
+        (&5, &[13, 21][..], &[34, 55], &89),
+    );
+    assert_eq!(
+        multindex!(arr; .., end - 1),
+        (&[3, 5, 8, 13, 21, 34, 55][..], &89)
+    );
+
+    // The middle range is empty when the slice is exactly long enough
+    assert_eq!(
+        multindex!(arr[..4]; ..2, .., end - 2..),
+        (&[3, 5], &[][..], &[8, 13]),
+    );
+
+    {
+        let (header, body, trailer) = multindex_mut!(arr; ..2, .., end - 2..);
+        header.copy_from_slice(&[0, 1]);
+        body.iter_mut().for_each(|x| *x = 2);
+        *trailer = [3, 4];
+    }
+    assert_eq!(arr, [0, 1, 2, 2, 2, 2, 3, 4]);
+}
+
+#[test]
+fn from_end_indexing_short_slices() {
+    let mut arr = [3u8, 5, 8, 13, 21, 34, 55, 89];
+
+    assert_eq!(multiget!(arr[..3]; ..2, .., end - 2..), None);
+    assert_eq!(multiget!(arr[..1]; end - 2..), None);
+    assert_eq!(multiget!(arr[..0]; end - 1), None);
+    assert_eq!(
+        multiget_mut!(arr[..3]; 0, end - 2..),
+        Some((&mut 3, &mut [5, 8])),
+    );
+    assert_eq!(multiget_mut!(arr[..2]; 0..2, end - 1), None);
+    assert_eq!(multiget_mut!(arr[..4]; 0..2, end - 3), None);
+    assert_eq!(
+        multiget_mut!(arr[..4]; 0..2, end - 2),
+        Some((&mut [3, 5], &mut 8)),
+    );
+
+    assert_eq!(
+        try_multindex!(arr[..3]; 0, end - 4..),
+        Err(OutOfBounds {
+            slice_len: 3,
+            max_bounded_end: 4,
+            argument: 1,
+        }),
+    );
+}
+
+#[test]
+fn try_from_end_indexing_errors() {
+    let mut arr = [3u16, 5, 8, 13, 21, 34, 55];
+
+    let oob = |max_bounded_end, argument| OutOfBounds {
+        slice_len: 7,
+        max_bounded_end,
+        argument,
+    };
+
+    // Overlapping the arguments relative to the start of the slice
+    assert_eq!(try_multindex_mut!(arr; 0..4, end - 4..), Err(oob(8, 1)));
+    assert_eq!(try_multindex_mut!(arr; end - 4.., 0..4), Err(oob(8, 0)));
+    assert_eq!(try_multindex_mut!(arr; 0..2, 5, end - 2), Err(oob(8, 2)));
+    assert_eq!(
+        try_multindex_mut!(arr; 0..6, end - 3..end - 1),
+        Err(oob(9, 1))
+    );
+
+    // A range with an unbounded end before the argument relative to the end
+    assert_eq!(try_multindex_mut!(arr; ..4, .., end - 4..), Err(oob(8, 2)));
+    assert_eq!(try_multindex!(arr; 0, 5.., end - 3..), Err(oob(8, 2)));
+
+    // Starting before the start of the slice
+    assert_eq!(try_multindex_mut!(arr; end - 8..), Err(oob(8, 0)));
+    assert_eq!(try_multindex_mut!(arr; 0..4, end - 8..), Err(oob(12, 1)));
+    assert_eq!(try_multindex!(arr; 0..4, end - 8..), Err(oob(8, 1)));
+
+    assert_eq!(
+        try_multindex_mut!(arr; ..3, .., end - 4..),
+        Ok((&mut [3, 5, 8], &mut [][..], &mut [13, 21, 34, 55])),
+    );
+    assert_eq!(
+        try_multindex!(arr; 0..4, end - 4..),
+        Ok((&[3, 5, 8, 13], &[13, 21, 34, 55])),
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_end_indexing_overlap_panics() {
+    let mut arr = [3u8, 5, 8, 13, 21, 34, 55, 89];
+
+    let _ = multindex_mut!(arr[..5]; 0..3, end - 3..);
+}
+
+#[test]
+#[should_panic]
+fn from_end_indexing_middle_range_panics() {
+    let mut arr = [3u8, 5, 8, 13, 21, 34, 55, 89];
+
+    let _ = multindex_mut!(arr[..5]; ..3, .., end - 3..);
+}
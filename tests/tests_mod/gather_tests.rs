@@ -0,0 +1,120 @@
+use multindex::{
+    endian::{u16be, u32be},
+    gather,
+    pod::Pod,
+    scatter,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct NonCopy(u32);
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Pair<A, B>(A, B);
+
+unsafe impl<A: Pod, B: Pod> Pod for Pair<A, B> {}
+
+#[test]
+fn gather_tuples() {
+    let arr = [3u8, 5, 8, 13, 21, 34];
+
+    assert_eq!(gather!(arr; 0), (3,));
+    assert_eq!(gather!(arr; 3, 1, 0..2), (13, 5, [3, 5]));
+    // Overlapping arguments are allowed.
+    assert_eq!(gather!(arr; 1..=3, 2, 4..6,), ([5, 8, 13], 8, [21, 34]));
+
+    let two = 2;
+    assert_eq!(gather!(arr; dyn two, 0), (8, 3));
+
+    let vec = vec![NonCopy(0), NonCopy(1), NonCopy(2)];
+    assert_eq!(
+        gather!(vec; 2, 0..2),
+        (NonCopy(2), [NonCopy(0), NonCopy(1)])
+    );
+    assert_eq!(vec.len(), 3);
+}
+
+#[test]
+fn gather_arrays() {
+    let arr = [3u8, 5, 8, 13, 21, 34];
+
+    assert_eq!(gather!(arr; [5, 4, 3, 2, 1, 0]), [34, 21, 13, 8, 5, 3]);
+    assert_eq!(gather!(arr; [0..2, 4..6]), [[3, 5], [21, 34]]);
+}
+
+#[test]
+fn gather_typed() {
+    let bytes = [0u8, 0, 1, 2, 3, 4];
+
+    assert_eq!(gather!(bytes; u32be @ 0..4, 4), (0x0102, 3));
+    assert_eq!(gather!(bytes; [u16le @ 0..2, u16le @ 2..4]), [0, 0x0201]);
+
+    // The comma in the type doesn't split the argument.
+    assert_eq!(
+        gather!(bytes; cast<Pair<u16be, [u8; 2]>> @ 0..4, 5),
+        (Pair(u16be::new(0), [1, 2]), 4)
+    );
+}
+
+#[test]
+#[should_panic]
+fn gather_out_of_bounds() {
+    let arr = [3u8, 5, 8];
+    let _ = gather!(arr; 0, 3);
+}
+
+#[test]
+fn scatter_tuples() {
+    let mut arr = [0u8; 6];
+
+    scatter!(arr; 0 <= (3,));
+    scatter!(arr; 3, 1, 4..6 <= (13, 5, [21, 34]));
+    assert_eq!(arr, [3, 5, 0, 13, 21, 34]);
+
+    let (a, b) = gather!(arr; 0..2, 3);
+    scatter!(arr; 3..5, 2 <= (a, b * 2),);
+    assert_eq!(arr, [3, 5, 26, 3, 5, 34]);
+
+    let mut vec = vec![NonCopy(0), NonCopy(1), NonCopy(2)];
+    scatter!(vec; 2, 0 <= (NonCopy(4), NonCopy(5)));
+    assert_eq!(vec, [NonCopy(5), NonCopy(1), NonCopy(4)]);
+}
+
+#[test]
+fn scatter_arrays() {
+    let mut arr = [0u8; 6];
+
+    scatter!(arr; [5, 4, 3, 2, 1, 0] <= [0, 1, 2, 3, 4, 5]);
+    assert_eq!(arr, [5, 4, 3, 2, 1, 0]);
+
+    scatter!(arr; [0..2, 4..6] <= [[6, 7], [8, 9]]);
+    assert_eq!(arr, [6, 7, 3, 2, 8, 9]);
+
+    let mut vec = vec![NonCopy(0), NonCopy(1), NonCopy(2)];
+    scatter!(vec; [1, 2] <= [NonCopy(4), NonCopy(5)]);
+    assert_eq!(vec, [NonCopy(0), NonCopy(4), NonCopy(5)]);
+}
+
+#[test]
+fn scatter_typed() {
+    let mut bytes = [0u8; 6];
+
+    scatter!(bytes; u32be @ 0..4, 4..6 <= (0x0102_0304, [5, 6]));
+    assert_eq!(bytes, [1, 2, 3, 4, 5, 6]);
+
+    scatter!(bytes; [u16le @ 4..6, u16le @ 0..2] <= [1, 2]);
+    assert_eq!(bytes, [2, 0, 3, 4, 1, 0]);
+
+    scatter!(bytes; u32be @ 2..6 <= (u32be::new(7),));
+    assert_eq!(bytes, [2, 0, 0, 0, 0, 7]);
+
+    scatter!(bytes; cast<Pair<u16be, [u8; 2]>> @ 2..6, 0 <= (Pair(u16be::new(8), [9, 10]), 1));
+    assert_eq!(bytes, [1, 0, 0, 8, 9, 10]);
+}
+
+#[test]
+#[should_panic]
+fn scatter_out_of_bounds() {
+    let mut arr = [3u8, 5, 8];
+    scatter!(arr; 0, 3 <= (1, 2));
+}
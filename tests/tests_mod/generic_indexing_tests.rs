@@ -0,0 +1,82 @@
+use multindex::generic::{self, Array, Index, Range, RangeFrom, RangeFull, RangeTo};
+
+fn every_arg<const I: usize, const S: usize, const L: usize>(
+    slice: &mut [u16],
+) -> (&mut u16, &mut [u16], &mut [u16; L], &mut [u16], &mut [u16]) {
+    generic::index_mut::<(Index<I>, RangeFull, Array<S, L>, RangeTo<9>, RangeFrom<10>), _>(slice)
+}
+
+#[test]
+fn generic_indexing() {
+    let mut arr = [3u16, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377, 610];
+
+    {
+        let (a, b, c, d, e) = every_arg::<0, 4, 3>(&mut arr);
+        assert_eq!(*a, 3);
+        assert_eq!(b, [5, 8, 13]);
+        assert_eq!(*c, [21, 34, 55]);
+        assert_eq!(d, [89, 144]);
+        assert_eq!(e, [377, 610]);
+        *a += c.iter().sum::<u16>();
+    }
+    assert_eq!(arr[0], 113);
+
+    assert_eq!(
+        generic::index::<(Range<1, 3>, Array<2, 2>), _>(&arr),
+        (&[5, 8][..], &[8, 13])
+    );
+    assert_eq!(
+        generic::get::<(Index<1>, RangeFrom<11>), _>(&arr),
+        Some((&5, &[610][..]))
+    );
+    assert_eq!(
+        generic::get::<(Index<1>, RangeFrom<12>), _>(&arr),
+        Some((&5, &[][..]))
+    );
+    assert_eq!(generic::get::<(Index<1>, RangeFrom<13>), _>(&arr), None);
+    assert_eq!(generic::get::<(Array<10, 3>,), _>(&arr), None);
+
+    assert_eq!(
+        generic::get_mut::<(RangeTo<3>, Array<10, 2>), _>(&mut arr),
+        Some((&mut [113, 5, 8][..], &mut [377, 610]))
+    );
+    assert_eq!(generic::get_mut::<(Index<12>,), _>(&mut arr), None);
+}
+
+#[test]
+#[should_panic]
+fn generic_indexing_out_of_bounds() {
+    let arr = [3u16, 5, 8];
+    let _ = generic::index::<(Index<0>, Index<3>), _>(&arr);
+}
+
+#[test]
+fn generic_indexing_twelve_args() {
+    let mut arr = [0u8; 12];
+
+    type Args = (
+        Index<0>,
+        Index<1>,
+        Index<2>,
+        Index<3>,
+        Index<4>,
+        Index<5>,
+        Index<6>,
+        Index<7>,
+        Index<8>,
+        Index<9>,
+        Index<10>,
+        RangeFull,
+    );
+
+    let (a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, rest) =
+        generic::index_mut::<Args, _>(&mut arr);
+    for (i, elem) in [a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10]
+        .iter_mut()
+        .enumerate()
+    {
+        **elem = i as u8;
+    }
+    rest[0] = 11;
+    assert_eq!(arr, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+}
@@ -1,4 +1,7 @@
-use multindex::{multiget, multiget_mut, multindex, multindex_mut, std_const_fns::usize_m};
+use multindex::{
+    multiget, multiget_mut, multindex, multindex_mut, std_const_fns::usize_m, try_multindex,
+    try_multindex_mut, OutOfBounds,
+};
 
 use std::convert::TryInto;
 
@@ -86,6 +89,12 @@ macro_rules! index_with_all {
         fn slice_get_mut(array: &mut [u16]) -> Option<$expected_mut_ty> {
             multiget_mut!(array; $($index)*)
         }
+        fn slice_try(array: &[u16]) -> Result<$expected_ref_ty, OutOfBounds> {
+            try_multindex!(array; $($index)*)
+        }
+        fn slice_try_mut(array: &mut [u16]) -> Result<$expected_mut_ty, OutOfBounds> {
+            try_multindex_mut!(array; $($index)*)
+        }
 
         assert_eq_mono!(slice_index(&array), $expected_ref);
         assert_eq_mono!(slice_get(&array).unwrap(), $expected_ref);
@@ -93,6 +102,9 @@ macro_rules! index_with_all {
         assert_eq_mono!(slice_index_mut(&mut array), $expected_mut);
         assert_eq_mono!(slice_get_mut(&mut array).unwrap(), $expected_mut);
 
+        assert_eq_mono!(slice_try(&array).unwrap(), $expected_ref);
+        assert_eq_mono!(slice_try_mut(&mut array).unwrap(), $expected_mut);
+
 
         ////////////////////////////////////////////////////////////////////////
         //      Index out of bounds
@@ -130,6 +142,11 @@ macro_rules! index_with_all {
             assert_eq_mono!(tinyarr_get(&array[..end]), None);
             assert_eq_mono!(slice_get_mut(&mut array[..end]), None);
             assert_eq_mono!(tinyarr_get_mut(&mut array[..end]), None);
+
+            let err = slice_try(&array[..end]).unwrap_err();
+            assert_eq!(err.slice_len, end);
+            assert!(err.max_bounded_end > end, "{:?}", err);
+            assert_eq!(slice_try_mut(&mut array[..end]).unwrap_err(), err);
         }
 
 
@@ -242,3 +259,26 @@ fn aliasing_shared_indexing() {
         Some((&5, &13, &34, &[8, 13, 21, 34]))
     );
 }
+
+#[test]
+fn out_of_bounds_argument() {
+    let mut arr = [3u16, 5, 8, 13, 21, 34, 55];
+
+    let oob = |max_bounded_end, argument| OutOfBounds {
+        slice_len: 7,
+        max_bounded_end,
+        argument,
+    };
+
+    assert_eq!(try_multindex!(arr; 7), Err(oob(8, 0)));
+    assert_eq!(try_multindex!(arr; 0, 3..8, 6), Err(oob(8, 1)));
+    assert_eq!(try_multindex!(arr; 0, 3..8, 9..), Err(oob(9, 2)));
+    assert_eq!(try_multindex_mut!(arr; 0, 10, .., 20), Err(oob(21, 3)));
+    assert_eq!(try_multindex_mut!(arr; 20, 0..3), Err(oob(21, 0)));
+
+    assert_eq!(
+        try_multindex_mut!(arr; 0, 2..4, end - 2..),
+        Ok((&mut 3, &mut [8, 13], &mut [34, 55]))
+    );
+    assert_eq!(try_multindex_mut!(arr; 0, 2..4, end - 8..), Err(oob(12, 2)));
+}
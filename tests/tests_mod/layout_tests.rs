@@ -0,0 +1,103 @@
+use multindex::layout;
+
+layout! {
+    struct Record {
+        tag: 0,
+        id: 1..=4,
+        flags: ..6,
+        body: ..,
+    }
+}
+
+layout! {
+    exhaustive struct Packet {
+        tag: 0,
+        len: 1..3,
+        body: ..,
+    }
+}
+
+layout! {
+    struct Pair -> PairView, PairViewMut {
+        left: 3..5,
+        right: 0..2,
+    }
+}
+
+#[test]
+fn layout_min_len() {
+    assert_eq!(Record::MIN_LEN, 6);
+    assert_eq!(Pair::MIN_LEN, 5);
+}
+
+#[test]
+fn layout_view() {
+    let bytes = [3u8, 5, 8, 13, 21, 34, 55, 89];
+
+    let rec = Record::view(&bytes).unwrap();
+    assert_eq!(rec.tag, &3);
+    assert_eq!(rec.id, &[5, 8, 13, 21]);
+    assert_eq!(rec.flags, &[34]);
+    assert_eq!(rec.body, &[55, 89][..]);
+
+    let rec = Record::view(&bytes[..6]).unwrap();
+    assert_eq!(rec.body, &[][..]);
+
+    assert_eq!(Record::view(&bytes[..5]), None);
+
+    let pair = Pair::view(&bytes[..5]).unwrap();
+    assert_eq!(
+        pair,
+        PairView {
+            left: &[13, 21],
+            right: &[3, 5]
+        }
+    );
+    assert_eq!(Pair::view(&bytes[..4]), None);
+}
+
+#[test]
+fn layout_view_mut() {
+    let mut bytes = [3u8, 5, 8, 13, 21, 34, 55, 89];
+
+    {
+        let rec = Record::view_mut(&mut bytes).unwrap();
+        *rec.tag = 0;
+        rec.id.reverse();
+        rec.flags[0] += 1;
+        rec.body.swap(0, 1);
+    }
+    assert_eq!(bytes, [0, 21, 13, 8, 5, 35, 89, 55]);
+
+    {
+        let pair = Pair::view_mut(&mut bytes).unwrap();
+        core::mem::swap(pair.left, pair.right);
+    }
+    assert_eq!(bytes, [8, 5, 13, 0, 21, 35, 89, 55]);
+
+    assert!(Record::view_mut(&mut bytes[..5]).is_none());
+}
+
+#[test]
+fn layout_exhaustive() {
+    assert_eq!(Packet::MIN_LEN, 3);
+
+    let mut bytes = [3u8, 5, 8, 13, 21];
+
+    let packet = Packet::view_mut(&mut bytes).unwrap();
+    *packet.tag += 1;
+    packet.len.reverse();
+    packet.body[1] = 0;
+    assert_eq!(bytes, [4, 8, 5, 13, 0]);
+
+    assert_eq!(
+        Packet::view(&bytes[..3]),
+        Some(PacketRef {
+            tag: &4,
+            len: &[8, 5],
+            body: &[],
+        })
+    );
+    assert_eq!(Packet::view(&bytes[..2]), None);
+    assert_eq!(Packet::view_mut(&mut bytes[..2]), None);
+}
@@ -0,0 +1,86 @@
+use multindex::{multiget, multiget_mut, multindex, multindex_mut};
+
+fn stop_unwind(func: impl FnOnce()) -> std::thread::Result<()> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    catch_unwind(AssertUnwindSafe(func))
+}
+
+#[test]
+fn mixed_indexing() {
+    let mut arr = [3u16, 5, 8, 13, 21, 34, 55];
+    let (two, four) = (2, 4);
+
+    assert_eq!(
+        multindex!(arr; 0, dyn two, 3..4, dyn four..),
+        (&3, &8, &[13], &[21, 34, 55][..])
+    );
+    assert_eq!(
+        multiget!(arr; 0, dyn two, 3..4, dyn four..),
+        Some((&3, &8, &[13], &[21, 34, 55][..]))
+    );
+    assert_eq!(
+        multindex_mut!(arr; 0, dyn two, 3..4, dyn four..),
+        (&mut 3, &mut 8, &mut [13], &mut [21, 34, 55][..])
+    );
+    assert_eq!(
+        multiget_mut!(arr; 0, dyn two, 3..4, dyn four..),
+        Some((&mut 3, &mut 8, &mut [13], &mut [21, 34, 55][..]))
+    );
+
+    // The unbounded ranges are normalized using the constant arguments next to them.
+    assert_eq!(
+        multindex_mut!(arr; dyn ..two, 2, .., 5, dyn 6),
+        (&mut [3, 5][..], &mut 8, &mut [13, 21], &mut 34, &mut 55)
+    );
+
+    // Only dyn arguments
+    assert_eq!(
+        multindex_mut!(arr; dyn two..four, dyn ..two),
+        (&mut [8, 13][..], &mut [3, 5][..])
+    );
+
+    // Shared indexing allows overlap
+    assert_eq!(
+        multindex!(arr; 2..4, dyn two..four, dyn two),
+        (&[8, 13], &[8, 13][..], &8)
+    );
+}
+
+#[test]
+fn mixed_indexing_errors() {
+    let mut arr = [3u16, 5, 8, 13, 21, 34, 55];
+    let (two, four, seven) = (2, 4, 7);
+
+    // overlapping with constant arguments
+    assert_eq!(multiget_mut!(arr; 0..3, dyn two), None);
+    assert_eq!(multiget_mut!(arr; dyn two, 0..3), None);
+    assert_eq!(multiget_mut!(arr; dyn four.., 6), None);
+    assert_eq!(multiget_mut!(arr; 3..5, dyn four), None);
+    assert!(stop_unwind(|| {
+        multindex_mut!(arr; 0..3, dyn two);
+    })
+    .is_err());
+
+    // overlapping with other dyn arguments
+    assert_eq!(multiget_mut!(arr; dyn two..four, 0, dyn ..=two), None);
+    assert!(stop_unwind(|| {
+        multindex_mut!(arr; dyn two..four, 0, dyn ..=two);
+    })
+    .is_err());
+
+    // out of bounds dyn arguments
+    assert_eq!(multiget!(arr; 0, dyn seven), None);
+    assert_eq!(multiget_mut!(arr; 0, dyn two..seven + 1), None);
+    assert_eq!(multiget!(arr; 0, dyn seven + 1..), None);
+    assert!(stop_unwind(|| {
+        multindex!(arr; 0, dyn seven);
+    })
+    .is_err());
+    assert!(stop_unwind(|| {
+        multindex_mut!(arr; 0, dyn usize::MAX);
+    })
+    .is_err());
+
+    // out of bounds constant arguments
+    assert_eq!(multiget_mut!(arr; 7, dyn two), None);
+}
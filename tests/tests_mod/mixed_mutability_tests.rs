@@ -0,0 +1,78 @@
+use multindex::multindex_mixed;
+
+fn stop_unwind(func: impl FnOnce()) -> std::thread::Result<()> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    catch_unwind(AssertUnwindSafe(func))
+}
+
+#[test]
+fn mixed_mutability() {
+    let mut arr = [3u16, 5, 8, 13, 21, 34, 55];
+
+    assert_eq!(multindex_mixed!(arr; ref 0), (&3,));
+    assert_eq!(multindex_mixed!(arr; mut 0), (&mut 3,));
+
+    {
+        let (a, b, c, d) = multindex_mixed!(arr; ref 1..4, mut 0, ref 2, mut 4..);
+        assert_eq!((a, c), (&[5, 8, 13], &8));
+        *b = a.iter().sum();
+        d.iter_mut().for_each(|x| *x += *c);
+    }
+    assert_eq!(arr, [26, 5, 8, 13, 29, 42, 63]);
+
+    // The `ref` arguments can overlap each other.
+    {
+        let (all, first, mid, last) = multindex_mixed!(arr; ref 0..7, ref 0, ref 1..6, ref 6);
+        assert_eq!(all.len(), 7);
+        assert_eq!((first, mid, last), (&26, &[5, 8, 13, 29, 42], &63));
+    }
+}
+
+#[test]
+fn mixed_mutability_other_args() {
+    let mut bytes = [1u8, 2, 3, 4, 5, 6];
+    let (two, four) = (2, 4);
+
+    {
+        let (a, b, c, d) =
+            multindex_mixed!(bytes; ref u16be @ 0..2, mut dyn two, ref dyn 0..two, mut dyn four..);
+        assert_eq!((a, c), (258, &[1, 2][..]));
+        *b = c[0] + c[1];
+        d.reverse();
+    }
+    assert_eq!(bytes, [1, 2, 3, 4, 6, 5]);
+
+    {
+        let (a, b) = multindex_mixed!(bytes; mut u16le @ ..2, ref 2..4);
+        assert_eq!(b, &[3, 4]);
+        a.set(a.get() + 1);
+    }
+    assert_eq!(bytes, [2, 2, 3, 4, 6, 5]);
+}
+
+#[test]
+fn mixed_mutability_errors() {
+    let mut arr = [3u16, 5, 8, 13, 21, 34, 55];
+    let (zero, two) = (0, 2);
+
+    stop_unwind(|| {
+        let _ = multindex_mixed!(arr; mut 0, ref 9);
+    })
+    .unwrap_err();
+
+    // `mut` runtime arguments can't overlap `ref` arguments
+    stop_unwind(|| {
+        let _ = multindex_mixed!(arr; mut dyn two, ref 1..3);
+    })
+    .unwrap_err();
+
+    // `ref` runtime arguments can't overlap `mut` arguments
+    stop_unwind(|| {
+        let _ = multindex_mixed!(arr; mut 1..3, ref dyn two);
+    })
+    .unwrap_err();
+
+    // `ref` runtime arguments can overlap each other
+    let (a, b, c) = multindex_mixed!(arr; ref dyn zero..two, ref dyn zero, mut 3);
+    assert_eq!((a, b, c), (&[3, 5][..], &3, &mut 13));
+}
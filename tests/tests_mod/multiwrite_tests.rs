@@ -0,0 +1,86 @@
+use multindex::{endian::u32le, multiwrite};
+
+#[test]
+fn multiwrite_assign() {
+    let mut arr = [0u16; 10];
+
+    multiwrite!(arr; 9 = 10, 0 = 1, 2..5 = [3, 4, 5], 6..=7 = [7, 8]);
+    assert_eq!(arr, [1, 0, 3, 4, 5, 0, 7, 8, 0, 10]);
+
+    // Trailing unbounded ranges clone from anything that derefs to a slice
+    multiwrite!(arr; 0..2 = [20, 21], 7.. = vec![27, 28, 29]);
+    assert_eq!(arr, [20, 21, 3, 4, 5, 0, 7, 27, 28, 29]);
+
+    multiwrite!(arr; 1 = 31, .. = &[32u16; 8][..]);
+    assert_eq!(arr, [20, 31, 32, 32, 32, 32, 32, 32, 32, 32]);
+
+    // `dyn` arguments
+    let (start, end) = (3, 5);
+    multiwrite!(arr; dyn start = 43, dyn start + 1..end + 1 = [44, 45]);
+    assert_eq!(arr, [20, 31, 32, 43, 44, 45, 32, 32, 32, 32]);
+}
+
+#[test]
+fn multiwrite_fill() {
+    let mut arr = [1u8; 12];
+
+    multiwrite!(arr; 2..10 => 0, 0 => 5, 11.. => 7);
+    assert_eq!(arr, [5, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 7]);
+
+    let mut strings = vec![String::new(); 4];
+    multiwrite!(strings; 0..3 => "hi".to_string(), 3 = "bye".to_string());
+    assert_eq!(strings, ["hi", "hi", "hi", "bye"]);
+
+    // An array length that isn't supported without the "rust_1_57" feature,
+    // going through a slice.
+    let mut arr = [0u8; 40];
+    multiwrite!(arr; 1 => 1, 3.. => 2);
+    assert_eq!(arr[..4], [0, 1, 0, 2]);
+    assert!(arr[3..].iter().all(|&x| x == 2));
+}
+
+#[test]
+fn multiwrite_typed() {
+    let mut arr = [0u8; 8];
+
+    multiwrite!(arr; u32le @ 0..4 = 0x0403_0201, u32le @ 4..8 = u32le::new(5));
+    assert_eq!(arr, [1, 2, 3, 4, 5, 0, 0, 0]);
+}
+
+#[test]
+fn multiwrite_values_in_order() {
+    let mut arr = [0u8; 4];
+
+    let mut order = Vec::new();
+    let mut next = |x: u8| {
+        order.push(x);
+        x
+    };
+    multiwrite!(arr; 3 = next(3), 1..3 => next(1), 0 = next(0));
+    assert_eq!(order, [3, 1, 0]);
+    assert_eq!(arr, [0, 1, 1, 3]);
+}
+
+#[test]
+#[should_panic]
+fn multiwrite_out_of_bounds() {
+    let mut arr = [0u8; 4];
+    multiwrite!(arr; 0 = 1, 4 = 2);
+}
+
+#[test]
+#[should_panic]
+fn multiwrite_mismatched_slice_length() {
+    let mut arr = [0u8; 4];
+    multiwrite!(arr; 0 = 1, 1.. = [2, 3]);
+}
+
+#[test]
+fn multiwrite_out_of_bounds_writes_nothing() {
+    let mut arr = [0u8; 4];
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        multiwrite!(arr; 0 = 1, 4 = 2);
+    }));
+    assert!(res.is_err());
+    assert_eq!(arr, [0; 4]);
+}
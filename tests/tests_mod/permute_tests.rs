@@ -0,0 +1,54 @@
+use multindex::permute;
+
+/// The result of permuting `0..len`, computed without `permute`.
+fn expected(permutation: &[usize], len: usize) -> Vec<usize> {
+    let mut out: Vec<usize> = (0..len).collect();
+    for (i, &from) in permutation.iter().enumerate() {
+        out[i] = from;
+    }
+    out
+}
+
+#[test]
+fn permute_cycles() {
+    macro_rules! case {
+        ($len:expr; [$($index:expr),*]) => {{
+            let mut vec: Vec<usize> = (0..$len).collect();
+            permute!(vec; [$($index),*]);
+            assert_eq!(vec, expected(&[$($index),*], $len));
+        }};
+    }
+
+    case!(0; []);
+    case!(3; []);
+    case!(1; [0]);
+    case!(2; [1, 0]);
+    case!(4; [1, 0]);
+    case!(4; [2, 0, 1, 3]);
+    case!(4; [3, 2, 1, 0]);
+    case!(5; [1, 2, 3, 4, 0]);
+    case!(5; [4, 0, 1, 2, 3]);
+    // Multiple cycles
+    case!(8; [1, 0, 4, 2, 3, 5, 7, 6]);
+    case!(9; [6, 2, 8, 5, 0, 1, 3, 4, 7]);
+}
+
+#[test]
+fn permute_non_clone() {
+    #[derive(Debug, PartialEq)]
+    struct NonClone(&'static str);
+
+    let mut arr = [NonClone("r"), NonClone("g"), NonClone("b"), NonClone("a")];
+    permute!(arr; [2, 1, 0, 3,]);
+    assert_eq!(
+        arr,
+        [NonClone("b"), NonClone("g"), NonClone("r"), NonClone("a")]
+    );
+}
+
+#[test]
+#[should_panic]
+fn permute_out_of_bounds() {
+    let mut arr = [0u8; 3];
+    permute!(arr; [3, 2, 1, 0]);
+}
@@ -0,0 +1,100 @@
+use multindex::multindex_rest_mut;
+
+#[test]
+fn rest_indexing() {
+    let mut arr = [3u8, 5, 8, 13, 21, 34, 55, 89];
+
+    assert_eq!(
+        multindex_rest_mut!(arr; 2),
+        ((&mut 8,), [&mut [3, 5][..], &mut [13, 21, 34, 55, 89][..]]),
+    );
+
+    // The arguments can be in any order, the uncovered regions are in ascending order.
+    assert_eq!(
+        multindex_rest_mut!(arr; 6..8, 0, 3..=4),
+        (
+            (&mut [55, 89], &mut 3, &mut [13, 21]),
+            [&mut [5, 8][..], &mut [34][..], &mut [][..]],
+        ),
+    );
+
+    // Adjacent arguments leave no region between them.
+    assert_eq!(
+        multindex_rest_mut!(arr; 1..3, 3, 5),
+        (
+            (&mut [5, 8], &mut 13, &mut 34),
+            [&mut [3][..], &mut [21][..], &mut [55, 89][..]],
+        ),
+    );
+
+    // Empty ranges don't split the regions.
+    assert_eq!(
+        multindex_rest_mut!(arr; 2..2, 5),
+        (
+            (&mut [], &mut 34),
+            [&mut [3, 5, 8, 13, 21][..], &mut [55, 89][..]]
+        ),
+    );
+}
+
+#[test]
+fn rest_indexing_unbounded() {
+    let mut arr = [3u8, 5, 8, 13, 21, 34, 55, 89];
+
+    let ((rest,), uncovered) = multindex_rest_mut!(arr; 5..);
+    assert_eq!(rest, &mut [34, 55, 89][..]);
+    assert_eq!(uncovered, [&mut [3, 5, 8, 13, 21][..]]);
+
+    let ((all,), uncovered) = multindex_rest_mut!(arr; ..);
+    assert_eq!(all.len(), 8);
+    let _: [&mut [u8]; 0] = uncovered;
+
+    let ((first, mid, rest), [gap]) = multindex_rest_mut!(arr; 0, 3..5, ..);
+    assert_eq!(
+        (first, mid, rest),
+        (&mut 3, &mut [13, 21], &mut [34, 55, 89][..])
+    );
+    assert_eq!(gap, &mut [5, 8][..]);
+}
+
+#[test]
+fn rest_indexing_writes() {
+    let mut vec = (0u16..10).collect::<Vec<u16>>();
+
+    {
+        let ((first, mid), uncovered) = multindex_rest_mut!(vec; 0, 4..6);
+        *first = 100;
+        mid.reverse();
+        for region in uncovered {
+            region.iter_mut().for_each(|x| *x *= 2);
+        }
+    }
+    assert_eq!(vec, [100, 2, 4, 6, 5, 4, 12, 14, 16, 18]);
+
+    let ((), [all]) = multindex_rest_mut!(vec;);
+    all[0] = 0;
+    assert_eq!(vec[0], 0);
+
+    let ((), [all]) = multindex_rest_mut!(&mut vec[..2];);
+    assert_eq!(all, &mut [0, 2]);
+}
+
+#[test]
+fn rest_indexing_typed() {
+    let mut bytes = [1u8, 0, 2, 10, 20];
+
+    let ((len,), [tag, body]) = multindex_rest_mut!(bytes; u16be @ 1..3);
+    len.set(len.get() + 1);
+    tag[0] = 9;
+    body.reverse();
+
+    assert_eq!(bytes, [9, 0, 3, 20, 10]);
+}
+
+#[test]
+#[should_panic]
+fn rest_indexing_out_of_bounds() {
+    let mut arr = [3u8, 5, 8];
+
+    let _ = multindex_rest_mut!(arr; 0, 2..4);
+}
@@ -0,0 +1,76 @@
+use multindex::layout_of;
+
+use core::mem::size_of;
+
+#[repr(C)]
+#[allow(dead_code)]
+struct Point {
+    x: u16,
+    y: u16,
+}
+
+#[repr(C)]
+#[allow(dead_code)]
+struct Shape {
+    kind: u8,
+    // 3 bytes of padding
+    area: u32,
+    center: Point,
+    tag: u8,
+    // 3 bytes of trailing padding
+}
+
+layout_of! {
+    struct ShapeLayout -> ShapeRef, ShapeMut for Shape {
+        kind,
+        area,
+        center,
+        tag,
+    }
+}
+
+layout_of! {
+    // Fields can be listed in any order, and skipped.
+    struct PartialLayout -> PartialRef, PartialMut for Shape {
+        center,
+        kind,
+    }
+}
+
+#[test]
+fn struct_layout_min_len() {
+    assert_eq!(size_of::<Shape>(), 16);
+    assert_eq!(ShapeLayout::MIN_LEN, 13);
+    assert_eq!(PartialLayout::MIN_LEN, 12);
+}
+
+#[test]
+fn struct_layout_view() {
+    let bytes: [u8; 16] = [1, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0, 0, 0];
+
+    let shape: ShapeRef<'_> = ShapeLayout::view(&bytes).unwrap();
+    assert_eq!(shape.kind, &[1]);
+    assert_eq!(shape.area, &[2, 3, 4, 5]);
+    assert_eq!(shape.center, &[6, 7, 8, 9]);
+    assert_eq!(shape.tag, &[10]);
+
+    assert!(ShapeLayout::view(&bytes[..13]).is_some());
+    assert!(ShapeLayout::view(&bytes[..12]).is_none());
+
+    let partial: PartialRef<'_> = PartialLayout::view(&bytes[..12]).unwrap();
+    assert_eq!(partial.center, &[6, 7, 8, 9]);
+    assert_eq!(partial.kind, &[1]);
+}
+
+#[test]
+fn struct_layout_view_mut() {
+    let mut bytes = [0u8; 13];
+
+    let shape: ShapeMut<'_> = ShapeLayout::view_mut(&mut bytes).unwrap();
+    shape.kind[0] = 3;
+    *shape.area = 0x0102_0304u32.to_be_bytes();
+    shape.center.copy_from_slice(&[5, 6, 7, 8]);
+    shape.tag[0] = 9;
+
+    assert_eq!(bytes, [3, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+}
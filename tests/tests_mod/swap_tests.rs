@@ -0,0 +1,77 @@
+use multindex::{multicopy_within, multirotate, multiswap};
+
+#[test]
+fn swap_ranges() {
+    let mut arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+    multiswap!(arr; 0..3, 7..10);
+    assert_eq!(arr, [7, 8, 9, 3, 4, 5, 6, 0, 1, 2]);
+
+    // The order of the arguments doesn't matter.
+    multiswap!(arr; 5..=6, 0..2);
+    assert_eq!(arr, [5, 6, 9, 3, 4, 7, 8, 0, 1, 2]);
+
+    // Unbounded starts are normalized before comparing lengths.
+    multiswap!(arr; 3..5, ..7,);
+    assert_eq!(arr, [5, 6, 9, 7, 8, 3, 4, 0, 1, 2]);
+
+    multiswap!(arr; 9, 0);
+    assert_eq!(arr, [2, 6, 9, 7, 8, 3, 4, 0, 1, 5]);
+
+    // Empty ranges
+    multiswap!(arr; 0..0, 4..4);
+    assert_eq!(arr, [2, 6, 9, 7, 8, 3, 4, 0, 1, 5]);
+
+    // Arguments computed from constants
+    const N: usize = 2;
+    multiswap!(arr; N..N + 2, N * 3..8);
+    assert_eq!(arr, [2, 6, 4, 0, 8, 3, 9, 7, 1, 5]);
+
+    let mut vec = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    multiswap!(vec; 0, 2);
+    assert_eq!(vec, ["c", "b", "a"]);
+}
+
+#[test]
+#[should_panic]
+fn swap_out_of_bounds() {
+    let mut arr = [0u8; 4];
+    multiswap!(arr; 0..2, 3..5);
+}
+
+#[test]
+fn rotate_ranges() {
+    let mut arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+    multirotate!(arr; 0..3, 3..6, 6..9);
+    assert_eq!(arr, [6, 7, 8, 0, 1, 2, 3, 4, 5, 9]);
+
+    multirotate!(arr; 9, 0, 5, 2);
+    assert_eq!(arr, [9, 7, 2, 0, 1, 6, 3, 4, 5, 8]);
+
+    // A single argument is left unchanged.
+    multirotate!(arr; 4..8);
+    assert_eq!(arr, [9, 7, 2, 0, 1, 6, 3, 4, 5, 8]);
+
+    // Two arguments are swapped.
+    multirotate!(arr; 0..2, 8..10);
+    assert_eq!(arr, [5, 8, 2, 0, 1, 6, 3, 4, 9, 7]);
+}
+
+#[test]
+fn copy_within_ranges() {
+    let mut arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+    multicopy_within!(arr; 0..3 => 7..10);
+    assert_eq!(arr, [0, 1, 2, 3, 4, 5, 6, 0, 1, 2]);
+
+    multicopy_within!(arr; 3 => 0, 4..=5 => 1..3, 6 => 9,);
+    assert_eq!(arr, [3, 4, 5, 3, 4, 5, 6, 0, 1, 6]);
+}
+
+#[test]
+#[should_panic]
+fn copy_within_out_of_bounds() {
+    let mut arr = [0u8; 4];
+    multicopy_within!(arr; 0 => 1, 2 => 4);
+}
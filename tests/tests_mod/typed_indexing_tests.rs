@@ -0,0 +1,78 @@
+use multindex::{
+    endian::{f32le, i16le, u16be, u32be, u32le},
+    multiget, multiget_mut, multindex, multindex_mut, try_multindex,
+};
+
+#[test]
+fn typed_indexing() {
+    let bytes = [0x12u8, 0x34, 0x56, 0x78, 0xFF, 0xFE, 0, 0, 0x80, 0x3F];
+
+    assert_eq!(multindex!(bytes; u32be @ 0..4), (0x1234_5678,));
+    assert_eq!(multindex!(bytes; u32le @ 0..4), (0x7856_3412,));
+    assert_eq!(
+        multindex!(bytes; u16be @ ..2, 2, i16le @ 4..=5, f32le @ 6..10),
+        (0x1234, &0x56, -257, 1.0),
+    );
+
+    // Typed arguments can be mixed with dyn arguments, and unbounded ranges.
+    let three = 3;
+    assert_eq!(
+        multindex!(bytes; dyn three, i16le @ 4..6, ..),
+        (&0x78, -257, &[0, 0, 0x80, 0x3F][..]),
+    );
+
+    // Shared indexing allows overlap
+    assert_eq!(
+        multindex!(bytes; u16be @ 0..2, u32be @ 0..4),
+        (0x1234, 0x1234_5678),
+    );
+
+    assert_eq!(multiget!(bytes; u16be @ 8..10), Some((0x803F,)));
+    assert_eq!(multiget!(bytes; u16be @ 9..11), None);
+    assert_eq!(try_multindex!(bytes[..4]; u32be @ 0..4), Ok((0x1234_5678,)));
+    assert!(try_multindex!(bytes[..3]; u32be @ 0..4).is_err());
+}
+
+#[test]
+fn typed_indexing_mut() {
+    let mut bytes = [0u8; 10];
+
+    {
+        let (a, b, c, d): (&mut u16be, &mut u8, &mut i16le, &mut f32le) =
+            multindex_mut!(bytes; u16be @ ..2, 2, i16le @ 4..=5, f32le @ 6..10);
+        a.set(0x1234);
+        *b = 7;
+        c.set(-2);
+        d.set(1.0);
+
+        assert_eq!((a.get(), c.get(), d.get()), (0x1234, -2, 1.0));
+    }
+    assert_eq!(bytes, [0x12, 0x34, 7, 0, 0xFE, 0xFF, 0, 0, 0x80, 0x3F]);
+
+    {
+        let (a, b) = multiget_mut!(bytes; u32le @ 0..4, u32le @ 4..8).unwrap();
+        core::mem::swap(a, b);
+    }
+    assert_eq!(bytes, [0xFE, 0xFF, 0, 0, 0x12, 0x34, 7, 0, 0x80, 0x3F]);
+
+    assert!(multiget_mut!(bytes; u32le @ 8..12).is_none());
+}
+
+#[test]
+fn endian_types() {
+    let mut x = u32be::new(0x0102_0304);
+    assert_eq!(x.to_bytes(), [1, 2, 3, 4]);
+    assert_eq!(x, u32be::from_bytes([1, 2, 3, 4]));
+    assert_eq!(format!("{:?}", x), "u32be(16909060)");
+
+    x.set(5);
+    assert_eq!(x.get(), 5);
+    assert_eq!(x.to_bytes(), [0, 0, 0, 5]);
+
+    let mut bytes = [1, 0];
+    u16be::from_bytes_mut(&mut bytes).set(0x0203);
+    assert_eq!(bytes, [2, 3]);
+    assert_eq!(u16be::from_bytes_ref(&bytes).get(), 0x0203);
+    assert_eq!(i16le::from(-1).to_bytes(), [0xFF, 0xFF]);
+    assert_eq!(u32le::SIZE, 4);
+}
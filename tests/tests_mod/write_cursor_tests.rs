@@ -0,0 +1,117 @@
+use multindex::{
+    endian::{u16be, u32be},
+    put, WriteCursor,
+};
+
+#[test]
+fn write_cursor_put() {
+    let mut arr = [0u16; 8];
+    let mut cursor = WriteCursor::new(&mut arr[..]);
+
+    // Advances past the furthest argument, regardless of their order.
+    assert_eq!(put!(cursor; 2 = 8, 0 = 3), Some(()));
+    assert_eq!((cursor.position(), cursor.len()), (3, 5));
+
+    assert_eq!(put!(cursor; 0..2 = [13, 21], 2 = 34), Some(()));
+    assert_eq!(cursor.position(), 6);
+
+    // Out of bounds arguments leave the cursor and the slice untouched.
+    assert_eq!(put!(cursor; 0 = 55, 2 = 89), None);
+    assert_eq!(put!(cursor; 1..3 = [55, 89]), None);
+    assert_eq!(cursor.position(), 6);
+    assert_eq!(cursor.rest(), &[0, 0]);
+
+    assert_eq!(put!(cursor; 1 = 89, 0 = 55,), Some(()));
+    assert!(cursor.is_empty());
+
+    // Empty ranges don't advance the cursor
+    assert_eq!(put!(cursor; ..0 = []), Some(()));
+    assert_eq!(cursor.position(), 8);
+
+    assert_eq!(arr, [3, 0, 8, 13, 21, 34, 55, 89]);
+}
+
+#[test]
+fn write_cursor_values_in_order() {
+    let mut arr = [0u8; 4];
+    let mut cursor = WriteCursor::new(&mut arr[..]);
+
+    let mut order = Vec::new();
+    let mut next = |x: u8| {
+        order.push(x);
+        x
+    };
+    assert_eq!(
+        put!(cursor; 3 = next(3), 1 = next(1), 0 = next(0)),
+        Some(())
+    );
+    assert_eq!(order, [3, 1, 0]);
+    assert_eq!(arr, [0, 1, 0, 3]);
+}
+
+#[test]
+fn write_cursor_typed() {
+    let mut arr = [0u8; 9];
+    let mut cursor = WriteCursor::new(&mut arr[..]);
+
+    assert_eq!(
+        put!(cursor; u32be @ ..4 = 0x01020304, u16le @ 4..6 = 0x0506u16),
+        Some(())
+    );
+    assert_eq!(put!(cursor; u32be @ 0..4 = u32be::new(7)), None);
+    assert_eq!(put!(cursor; 2 = 7, u16be @ 0..2 = u16be::new(8)), Some(()));
+    assert!(cursor.is_empty());
+
+    assert_eq!(arr, [1, 2, 3, 4, 6, 5, 0, 8, 7]);
+}
+
+#[test]
+fn write_cursor_skip() {
+    let mut arr = [0u8; 5];
+    let mut cursor = WriteCursor::new(&mut arr[..]);
+
+    assert_eq!(cursor.skip(6), None);
+    cursor.skip(2).unwrap().copy_from_slice(&[1, 2]);
+    assert_eq!(cursor.position(), 2);
+
+    cursor.rest_mut()[0] = 3;
+    assert_eq!(put!(cursor; 1 = 4), Some(()));
+
+    let rest = cursor.into_rest();
+    assert_eq!(rest, &mut [0][..]);
+    rest[0] = 5;
+
+    assert_eq!(arr, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn write_cursor_by_reference() {
+    fn put_pair(cursor: &mut WriteCursor<'_, u8>, a: u8, b: u8) -> Option<()> {
+        put!(*cursor; 0 = a, 1 = b)
+    }
+
+    let mut arr = [0u8; 5];
+    let mut cursor = WriteCursor::new(&mut arr[..]);
+    assert_eq!(put_pair(&mut cursor, 1, 2), Some(()));
+    assert_eq!(put_pair(&mut cursor, 3, 4), Some(()));
+    assert_eq!(put_pair(&mut cursor, 5, 6), None);
+    assert_eq!(
+        format!("{:?}", cursor),
+        "WriteCursor { position: 4, rest: [0] }"
+    );
+
+    assert_eq!(arr, [1, 2, 3, 4, 0]);
+}
+
+#[test]
+fn write_cursor_fill() {
+    let mut arr = [1u8; 6];
+    let mut cursor = WriteCursor::new(&mut arr[..]);
+
+    assert_eq!(put!(cursor; 0..3 => 0, 3 = 9), Some(()));
+    assert_eq!(put!(cursor; 0..3 => 0), None);
+    assert_eq!(put!(cursor; .. => 5), Some(()));
+    assert!(cursor.is_empty());
+
+    assert_eq!(arr, [0, 0, 0, 9, 5, 5]);
+}